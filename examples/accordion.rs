@@ -0,0 +1,164 @@
+//This example shows the accordion in action
+//it will show 3 sections, two of them with a single button to change the background color.
+//Press 3 to open or close the third section using the channel
+
+use mergui::widgets::{AccordionConfig, AccordionSection, ButtonConfig};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, Image, VectorFont},
+    input::{Event, Key},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Accordion",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+    //then, turn it into a MFont. This is done so we can clone the font, which is something we need.
+    let font = MFont::from_font(&base_font, &gfx, 15.0)?;
+
+    //we need an image for the headers and buttons
+    let button = Image::load(&gfx, "button.png").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //every section gets its own layer. The widgets on it are only shown while the section is open
+    //The headers are 50 high and start at y 100, so the content of the first section starts at y 150
+    let mut first_layer = context.add_singular_layer();
+    let mut to_cyan_button = first_layer.add_widget(ButtonConfig {
+        background: button.clone(),
+        background_location: Rectangle::new(Vector::new(110., 155.), Vector::new(100., 50.)),
+        blend_color: Some(Color::GREEN),
+        hover_color: Some(Color::RED),
+        font_style: FontStyle {
+            font: font.clone(),
+            location: Vector::new(20., 30.),
+            color: Color::WHITE,
+        },
+        text: "To Cyan".into(),
+    });
+    //the content of a section is placed as if every section above it is closed.
+    //So, the content of the second section starts at y 200. The accordion moves it down when the first section is open
+    let mut second_layer = context.add_singular_layer();
+    let mut to_purple_button = second_layer.add_widget(ButtonConfig {
+        background: button.clone(),
+        background_location: Rectangle::new(Vector::new(110., 205.), Vector::new(100., 50.)),
+        blend_color: Some(Color::GREEN),
+        hover_color: Some(Color::RED),
+        font_style: FontStyle {
+            font: font.clone(),
+            location: Vector::new(20., 30.),
+            color: Color::WHITE,
+        },
+        text: "To Purple".into(),
+    });
+    let third_layer = context.add_singular_layer();
+
+    let config = AccordionConfig {
+        sections: vec![
+            AccordionSection {
+                title: "First".into(),
+                layer: first_layer,
+                //the sections below move down by this much when this section is open
+                content_height: 60.,
+            },
+            AccordionSection {
+                title: "Second".into(),
+                layer: second_layer,
+                content_height: 60.,
+            },
+            AccordionSection {
+                title: "Third".into(),
+                layer: third_layer,
+                content_height: 0.,
+            },
+        ],
+        location: Vector::new(100., 100.),
+        header_size: Vector::new(200., 50.),
+        background: button,
+        font_style: FontStyle {
+            font,
+            location: Vector::new(25., 30.), //relative to the header
+            color: Color::WHITE,
+        },
+        blend_color: Some(Color::from_rgba(100, 100, 100, 1.0)),
+        hover_color: Some(Color::RED),
+        //the headers of open sections are drawn in this color
+        open_color: Color::GREEN,
+        //set this to false to close the other sections when one gets opened
+        allow_multiple_open: true,
+        //the sections that start open
+        open: vec![0],
+    };
+    let mut accordion = layer.add_widget(config);
+
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+    //this variable is used to change the background color when one of the hidden buttons gets clicked
+    let mut background_color = Color::WHITE;
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+
+            if to_cyan_button.channel.has_clicked() {
+                background_color = if background_color == Color::CYAN {
+                    Color::WHITE
+                } else {
+                    Color::CYAN
+                }
+            }
+            if to_purple_button.channel.has_clicked() {
+                background_color = if background_color == Color::PURPLE {
+                    Color::WHITE
+                } else {
+                    Color::PURPLE
+                }
+            }
+            //the channel can open and close sections as well
+            if let Event::KeyboardInput(key) = &e {
+                if key.key() == Key::Key3 && key.is_down() {
+                    let is_open = accordion.channel.is_open(2);
+                    accordion.channel.set_open(2, !is_open);
+                }
+            }
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            //check if we need to draw a white_background or not
+            gfx.clear(background_color);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -123,6 +123,8 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             text: "Concealer".into(),
         },
         layer: concealed_layer,
+        is_popover: false,
+        open_on_hover: None,
     };
     let _concealer = layer.add_widget(conf);
 
@@ -136,6 +138,9 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         location: Rectangle::new(Vector::new(100., 160.), Vector::new(260., 50.)),
         start_value: Some(String::from("Text box")),
         cursor_config: Default::default(),
+        validation: Default::default(),
+        mask: None,
+        suggestions: None,
     };
     let _text_input = layer.add_widget(config);
 
@@ -166,6 +171,8 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         selected: Some(0),
         divider_color: Color::BLACK,
         divider_size: 5.0,
+        max_visible: None,
+        open_direction: Default::default(),
         t: PhantomData,
     };
     let _dropdown = layer.add_widget(conf);
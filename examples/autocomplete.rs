@@ -0,0 +1,105 @@
+//This example shows how to give a text input field suggestions while the user is typing
+//The suggested items are shown below the input and can be picked using the mouse, the arrow keys or tab.
+
+use mergui::widgets::{InputConfig, SuggestionConfig};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Autocomplete",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //These are the items we want to suggest.
+    let items = ["Sword", "Shield", "Short bow", "Long bow", "Staff", "Spear"];
+
+    //Now, we get to the input widget. This is a widget that allows the user to insert some text.
+    let config = InputConfig {
+        font: FontStyle {
+            font: MFont::from_font(&base_font, &gfx, 30.0)?,
+            location: Vector::new(100., 20.),
+            color: Color::BLACK,
+        },
+        placeholder: None,
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(260., 50.)),
+        start_value: None,
+        cursor_config: Default::default(),
+        validation: Default::default(),
+        mask: None,
+        //this makes the input show suggestions
+        suggestions: Some(SuggestionConfig {
+            //this function gets the current value and returns what should be suggested
+            provider: Box::new(move |value| {
+                let value = value.to_lowercase();
+                items
+                    .iter()
+                    .filter(|item| !value.is_empty() && item.to_lowercase().starts_with(&value))
+                    .map(|item| item.to_string())
+                    .collect()
+            }),
+            //how high every suggestion is
+            option_height: 40.0,
+            //how many suggestions are shown at most
+            max_shown: 5,
+            background_color: Color::WHITE,
+            //the color of the suggestion that is selected or hovered over
+            highlight_color: Color::from_rgba(200, 200, 255, 1.0),
+            divider_color: Color::BLACK,
+        }),
+    };
+    let _text_input = layer.add_widget(config);
+
+    // Time to render the input
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//This example shows how to create checkboxes and toggle switches and how to read their state
+
+use mergui::{
+    channels::CheckState,
+    widgets::{CheckboxConfig, CheckboxLook, LabelConfig, ToggleConfig, ToggleLook},
+};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Checkbox",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    let label_style = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 30.0)?,
+        //the location of the label is relative to the widget
+        location: Vector::new(50., 30.),
+        color: Color::BLACK,
+    };
+
+    //A checkbox. It can be checked, unchecked or indeterminate. The user can only switch between checked and unchecked
+    let config = CheckboxConfig {
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(35., 35.)),
+        //we let mergui draw it, but images can also be used
+        look: CheckboxLook::Drawn {
+            border_color: Color::BLACK,
+            check_color: Color::BLUE,
+        },
+        //text that is drawn next to the checkbox. Clicking on it toggles the checkbox as well
+        label: Some(LabelConfig {
+            text: "Enable sound".into(),
+            font_style: label_style.clone(),
+        }),
+        state: CheckState::Checked,
+    };
+    let sound = layer.add_widget(config);
+
+    //A toggle works the same, but looks like a switch
+    let config = ToggleConfig {
+        location: Rectangle::new(Vector::new(100., 120.), Vector::new(70., 35.)),
+        look: ToggleLook::Drawn {
+            off_color: Color::from_rgba(200, 200, 200, 1.0),
+            on_color: Color::GREEN,
+            knob_color: Color::WHITE,
+        },
+        label: Some(LabelConfig {
+            text: "Dark mode".into(),
+            font_style: FontStyle {
+                location: Vector::new(85., 30.),
+                ..label_style
+            },
+        }),
+        is_on: false,
+    };
+    let mut dark_mode = layer.add_widget(config);
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //dark mode can't be turned on without sound. The channel can also change the state
+        if !sound.channel.is_checked() {
+            dark_mode.channel.set_checked(false);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(if dark_mode.channel.is_checked() {
+                Color::from_rgba(100, 100, 100, 1.0)
+            } else {
+                Color::WHITE
+            });
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//This example shows how to create a combo box. A text field with a list of options that gets filtered while typing
+//The options can be picked using the mouse, the arrow keys or tab. Clicking on the field or pressing down shows every option.
+
+use mergui::{
+    channels::{ComboBoxValue, InputEvent},
+    widgets::{combo_box::ComboBoxFilter, ComboBoxConfig},
+};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Combo box",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //Now, we get to the combo box. The user can type to filter the options and then pick one, or just type something else.
+    let config = ComboBoxConfig {
+        font: FontStyle {
+            font: MFont::from_font(&base_font, &gfx, 30.0)?,
+            location: Vector::new(100., 20.),
+            color: Color::BLACK,
+        },
+        placeholder: None,
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(260., 50.)),
+        cursor_config: Default::default(),
+        //the values that can be picked and the text that the user sees for them
+        options: vec![
+            (1, "Sword".into()),
+            (2, "Shield".into()),
+            (3, "Short bow".into()),
+            (4, "Long bow".into()),
+            (5, "Staff".into()),
+            (6, "Spear".into()),
+        ],
+        //what is picked at the start (if any)
+        selected: None,
+        //fuzzy matching means that "sbw" shows "Short bow"
+        filter: ComboBoxFilter::Fuzzy,
+        //if the user may type something that isn't an option
+        allow_free_text: true,
+        //how high every option is
+        option_height: 40.0,
+        //how many options are shown at most
+        max_shown: 5,
+        background_color: Color::WHITE,
+        //the color of the option that is selected or hovered over
+        highlight_color: Color::from_rgba(200, 200, 255, 1.0),
+        divider_color: Color::BLACK,
+    };
+    let mut combo_box = layer.add_widget(config);
+
+    // Time to render the combo box
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the channel tells us if the value got picked from the list or was typed
+        for event in combo_box.channel.events() {
+            if let InputEvent::FocusLost = event {
+                match combo_box.channel.get_value() {
+                    Some(ComboBoxValue::Selected(id)) => println!("Picked item {}", id),
+                    Some(ComboBoxValue::Typed(text)) => println!("Typed {}", text),
+                    None => println!("Nothing picked"),
+                }
+            }
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -1,5 +1,5 @@
 //This example shows the concealer. The concealer is a widget that either hides or shows a layer when the user clicks on it.
-//For this example we are going to make a concealer that hides a button.
+//For this example we are going to make a concealer that hides a button and a popup that hides another.
 
 use quicksilver::{
     geom::{Rectangle, Vector},
@@ -12,6 +12,7 @@ use mergui::{
     widgets::{ButtonConfig, ConcealerConfig},
     Context, FontStyle, MFont,
 };
+use std::time::Duration;
 
 fn main() {
     run(
@@ -67,12 +68,16 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         },
         //the layer that the concealer is in control off.
         layer: hidden_layer,
+        //if this is true, clicking somewhere else or pressing escape also hides the layer
+        is_popover: false,
+        //if set, hovering over the button for this long also shows the layer
+        open_on_hover: None,
     };
 
     //now, add the conceiler to the layer.
     let mut concealer_return = layer.add_widget(concealer);
     //now, time to add our button
-    let mut button = concealer_return.channel.add_widget(ButtonConfig {
+    let mut to_cyan_button = concealer_return.channel.add_widget(ButtonConfig {
         background: button.clone(),
         background_location: Rectangle::new(Vector::new(205., 105.), Vector::new(100., 50.)),
         blend_color: Some(Color::GREEN),
@@ -85,6 +90,40 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         text: "To Cyan".into(),
     });
 
+    //A concealer can also work like a popup.
+    //This one opens when the mouse stays on the button for half a second and closes when clicking somewhere else
+    let popover = ConcealerConfig {
+        button: ButtonConfig {
+            background: button.clone(),
+            background_location: Rectangle::new(Vector::new(100., 210.), Vector::new(100., 50.)),
+            blend_color: Some(Color::GREEN),
+            hover_color: Some(Color::RED),
+            font_style: FontStyle {
+                font: font.clone(),
+                location: Vector::new(30., 30.),
+                color: Color::WHITE,
+            },
+            text: "More".into(),
+        },
+        layer: context.add_singular_layer(),
+        is_popover: true,
+        open_on_hover: Some(Duration::from_millis(500)),
+    };
+    let mut popover_return = layer.add_widget(popover);
+    //clicking on the widgets of the popup doesn't close it
+    let mut to_purple_button = popover_return.channel.add_widget(ButtonConfig {
+        background: button,
+        background_location: Rectangle::new(Vector::new(205., 210.), Vector::new(100., 50.)),
+        blend_color: Some(Color::GREEN),
+        hover_color: Some(Color::RED),
+        font_style: FontStyle {
+            font,
+            location: Vector::new(20., 30.),
+            color: Color::WHITE,
+        },
+        text: "To Purple".into(),
+    });
+
     gfx.clear(Color::WHITE);
     context.render(&mut gfx, &window)?;
     gfx.present(&window)?;
@@ -97,13 +136,20 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             //whenever we are given an event, we pass it to context so it can update the widget.
             context.event(&e, &window);
 
-            if button.channel.has_clicked() {
+            if to_cyan_button.channel.has_clicked() {
                 background_color = if background_color == Color::CYAN {
                     Color::WHITE
                 } else {
                     Color::CYAN
                 }
             }
+            if to_purple_button.channel.has_clicked() {
+                background_color = if background_color == Color::PURPLE {
+                    Color::WHITE
+                } else {
+                    Color::PURPLE
+                }
+            }
         }
         //limit the FPS to 60
         if render_timer.exhaust().is_some() {
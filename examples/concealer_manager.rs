@@ -1,11 +1,16 @@
 //This example shows the concealer manager in action
 //it will show 2 concealers that are mananged, each with a single button to change the background color.
+//The concealers can also be opened with the 1 and 2 keys, or closed with escape.
 
-use mergui::widgets::{ButtonConfig, ConcealerConfig, ConcealerManagerConfig};
+use mergui::{
+    channels::ConcealerManagerEvent,
+    widgets::{ButtonConfig, ConcealerConfig, ConcealerManagerConfig},
+};
 
 use quicksilver::{
     geom::{Rectangle, Vector},
     graphics::{Color, Graphics, Image, VectorFont},
+    input::{Event, Key},
     Result, Timer, {run, Input, Settings, Window},
 };
 
@@ -75,6 +80,9 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             text: "First".into(),
         },
         layer: first_layer,
+        //a manager also handles popovers and opening on hover, while making sure only one concealer is shown
+        is_popover: false,
+        open_on_hover: None,
     };
 
     let mut second_layer = context.add_singular_layer();
@@ -104,6 +112,8 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             text: "Second".into(),
         },
         layer: second_layer,
+        is_popover: false,
+        open_on_hover: None,
     };
     //now, with 2 concealers made. Lets add them to a ConcealerManager
     //first, make the manager config
@@ -111,7 +121,7 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         concealers: vec![first_concealer, second_concealer],
     };
     //now, add the manager to the layer.
-    let _concealer_manager_channel = layer.add_widget(manager_config);
+    let mut concealer_manager = layer.add_widget(manager_config);
     gfx.clear(Color::WHITE);
     context.render(&mut gfx, &window)?;
     gfx.present(&window)?;
@@ -124,6 +134,22 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             //whenever we are given an event, we pass it to context so it can update the widget.
             context.event(&e, &window);
 
+            //the channel can also change which concealer is active. This works the same as clicking on its button
+            if let Event::KeyboardInput(key) = &e {
+                let new_active = match key.key() {
+                    Key::Key1 => Some(Some(0)),
+                    Key::Key2 => Some(Some(1)),
+                    Key::Escape => Some(None),
+                    _ => None,
+                };
+                if let (true, Some(new_active)) = (key.is_down(), new_active) {
+                    concealer_manager.channel.set_active_concealer(new_active);
+                }
+            }
+            for ConcealerManagerEvent::Changed(active) in concealer_manager.channel.events() {
+                println!("Active concealer: {:?}", active);
+            }
+
             if to_cyan_button.channel.has_clicked() {
                 background_color = if background_color == Color::CYAN {
                     Color::WHITE
@@ -0,0 +1,126 @@
+//This example shows how to open a context menu and read what got chosen.
+//Right click anywhere to open the menu. It can also be used with the arrow keys, enter and escape
+
+use mergui::{
+    channels::ContextMenuEvent,
+    widgets::{ContextMenuConfig, ContextMenuItem},
+};
+
+use quicksilver::{
+    geom::Vector,
+    graphics::{Color, Graphics, VectorFont},
+    input::{Event, MouseButton},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Context menu",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+//the ids of the items, send back when the user chooses one
+#[derive(Clone, Debug)]
+enum Action {
+    Copy,
+    Paste,
+    Delete,
+    SortByName,
+    SortByDate,
+}
+
+fn menu_config(font: &MFont) -> ContextMenuConfig<Action> {
+    ContextMenuConfig {
+        items: vec![
+            //the shortcut is only drawn, the menu doesn't listen to it
+            ContextMenuItem::new(Action::Copy, "Copy").with_shortcut("Ctrl+C"),
+            //disabled items can't be chosen
+            ContextMenuItem::new(Action::Paste, "Paste")
+                .with_shortcut("Ctrl+V")
+                .with_disabled(true),
+            ContextMenuItem::new(Action::Delete, "Delete"),
+            //items with a submenu open it instead of being chosen. So, their id is never send back
+            ContextMenuItem::new(Action::SortByName, "Sort").with_submenu(vec![
+                ContextMenuItem::new(Action::SortByName, "By name"),
+                ContextMenuItem::new(Action::SortByDate, "By date"),
+            ]),
+        ],
+        //open at the cursor
+        location: None,
+        item_size: Vector::new(200., 30.),
+        font: font.clone(),
+        text_color: Color::BLACK,
+        background_color: Color::from_rgba(230, 230, 230, 1.0),
+        highlight_color: Color::from_rgba(150, 200, 255, 1.0),
+        border_color: Color::BLACK,
+    }
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    let font = MFont::from_font(&base_font, &gfx, 20.0)?;
+
+    //the context menu that is currently open, if any
+    let mut menu = None;
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+            //mergui doesn't open context menus by itself. Instead, we ask the context to open one when the right mouse button is pressed
+            if let Event::PointerInput(input) = &e {
+                if input.button() == MouseButton::Right && input.is_down() {
+                    menu = Some(context.open_context_menu(menu_config(&font)));
+                }
+            }
+        }
+        if let Some(menu) = &mut menu {
+            for event in menu.channel.events() {
+                match event {
+                    ContextMenuEvent::Selected(action) => println!("Chose {:?}", action),
+                    ContextMenuEvent::Toggled(action, checked) => {
+                        println!("Turned {:?} {}", action, if checked { "on" } else { "off" })
+                    }
+                    ContextMenuEvent::Closed => println!("Closed the menu"),
+                }
+            }
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
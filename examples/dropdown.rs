@@ -1,7 +1,9 @@
-//This example shows how to create a dropdown and how to get the current selected option
+//This example shows how to create a dropdown and how to get the current selected option.
+//The options are split into groups using headers and a separator, one of them has an icon and another one is disabled
 //It does so by changing the background to whatever color was selected
 
-use mergui::widgets::DropDownConfig;
+use mergui::channels::DropdownEvent;
+use mergui::widgets::{DropDownConfig, DropDownEntryKind, DropDownValueConfig, OpenDirection};
 
 use quicksilver::{
     geom::{Rectangle, Vector},
@@ -53,38 +55,31 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
     //we then construct a layer. A layer is used to group and control multiple widgets together.
     let mut layer = context.add_layer();
 
+    //the style the text of the options uses
+    let option_style = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 30.0)?,
+        location: Vector::new(20., 55.),
+        ..basic_font_style.clone()
+    };
+    //an option is made from the value once selected, the text that the user sees and the style the text uses
+    let option = |value: Color, text: &str| -> DropDownValueConfig<Color> {
+        (value, text.to_string(), option_style.clone()).into()
+    };
+
     //This gets a list of options and allows the user to select 1 of them.
     let conf = DropDownConfig {
         //the options that can be selected. It accepts either a DropDownValueConfig or something that supports .into() for it.
         values: vec![
-            (
-                Color::WHITE,   //the value once selected
-                "White".into(), //The text that the user sees
-                //the style the text uses
-                FontStyle {
-                    font: MFont::from_font(&base_font, &gfx, 30.0)?,
-                    location: Vector::new(20., 55.),
-                    ..basic_font_style.clone()
-                },
-            ),
-            (
-                Color::CYAN,
-                "Cyan".into(),
-                FontStyle {
-                    font: MFont::from_font(&base_font, &gfx, 35.0)?,
-                    location: Vector::new(20., 55.),
-                    ..basic_font_style.clone()
-                },
-            ),
-            (
-                Color::PURPLE,
-                "Purple".into(),
-                FontStyle {
-                    font: MFont::from_font(&base_font, &gfx, 35.0)?,
-                    location: Vector::new(20., 55.),
-                    ..basic_font_style.clone()
-                },
-            ),
+            //group headers and separators are shown in the list, but can't be selected. Their value is never used
+            option(Color::WHITE, "Light colors").with_kind(DropDownEntryKind::GroupHeader),
+            option(Color::WHITE, "White"),
+            option(Color::CYAN, "Cyan"),
+            option(Color::WHITE, "").with_kind(DropDownEntryKind::Separator),
+            option(Color::WHITE, "Dark colors").with_kind(DropDownEntryKind::GroupHeader),
+            //an icon is drawn left of the text
+            option(Color::PURPLE, "Purple").with_icon(button.clone()),
+            //disabled options are greyed out and can't be selected
+            option(Color::BLACK, "Black").with_disabled(true),
         ],
         //where the dropdown should be rendered
         location: Rectangle::new(Vector::new(100., 50.), Vector::new(160., 50.)),
@@ -95,14 +90,19 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         //how big this button should be
         open_button_size: (100.0, 50.0).into(),
         //what the default selected is (if any)
-        selected: Some(0),
+        selected: Some(1),
         //the color for the dividers from every option
         divider_color: Color::BLACK,
         //how big this divider should be
         divider_size: 5.0,
+        //how many options are visible at once before the list starts scrolling
+        max_visible: Some(4),
+        //in which direction the list opens. Auto opens it upwards if there is no room below it
+        open_direction: OpenDirection::Auto,
         t: PhantomData, //generic sadness :(
     };
-    let dropdown = layer.add_widget(conf);
+    let mut dropdown = layer.add_widget(conf);
+    let mut background = dropdown.channel.get_value().unwrap_or(Color::WHITE);
 
     gfx.clear(Color::WHITE);
     context.render(&mut gfx, &window)?;
@@ -115,10 +115,15 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
             //whenever we are given an event, we pass it to context so it can update the widget.
             context.event(&e, &window);
         }
+        //instead of asking for the value every frame, we can also react to the user selecting something else
+        for event in dropdown.channel.events() {
+            if let DropdownEvent::Changed(color) = event {
+                background = color;
+            }
+        }
         //limit the FPS to 60
         if render_timer.exhaust().is_some() {
-            //get the color we need to draw
-            gfx.clear(dropdown.channel.get_value().unwrap_or(Color::WHITE));
+            gfx.clear(background);
             //render the widgets
             context.render(&mut gfx, &window)?;
             //paint to the screen
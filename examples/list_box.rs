@@ -0,0 +1,103 @@
+//This example shows how to create a list box, where multiple values can be selected
+//It prints the selected party members whenever the selection changes
+
+use mergui::{channels::ListBoxEvent, widgets::ListBoxConfig};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+use std::marker::PhantomData;
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "List box",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    let font_style = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 30.0)?,
+        location: Vector::new(100., 20.),
+        color: Color::BLACK,
+    };
+
+    //Now, we get to the list box. A list where the user can select multiple values.
+    //Hold control to select or deselect a single member, shift to select a range or use the checkboxes.
+    let config = ListBoxConfig {
+        //the values that can be selected. Just like with the dropdown, these can be anything that turns into a DropDownValueConfig
+        values: [
+            "Alice", "Bob", "Carol", "Dave", "Eve", "Frank", "Grace", "Heidi",
+        ]
+        .iter()
+        .map(|name| (name.to_string(), font_style.clone()))
+        .collect(),
+        //if there are more values than fit inside this location, the list can be scrolled
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(260., 200.)),
+        option_height: 40.0,
+        //what starts as selected
+        selected: vec![0],
+        //the color behind selected values
+        selected_color: Color::from_rgba(200, 200, 255, 1.0),
+        //draw a checkbox in front of every value
+        show_checkboxes: true,
+        divider_color: Color::BLACK,
+        divider_size: 5.0,
+        t: PhantomData,
+    };
+    let mut party = layer.add_widget(config);
+
+    // Time to render the list box
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the list box tells us whenever the selection changed
+        for event in party.channel.events() {
+            let ListBoxEvent::Changed(members) = event;
+            println!("The party is now: {}", members.join(", "));
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//This example shows the menu bar of a small level editor and how to read what got chosen.
+//Click on a title to open its menu. While a menu is open, hovering over another title opens that one instead.
+//The menus can also be used with the arrow keys, enter and escape
+
+use mergui::{
+    channels::ContextMenuEvent,
+    widgets::{ContextMenuItem, MenuBarConfig, MenuBarEntry},
+};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Menu bar",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+//the ids of the items. Every menu of the bar sends them back through the same channel
+#[derive(Clone, Debug)]
+enum Action {
+    New,
+    Open,
+    Save,
+    Quit,
+    Undo,
+    Redo,
+    Separator,
+    ShowGrid,
+    Zoom(u8),
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    let font = MFont::from_font(&base_font, &gfx, 20.0)?;
+
+    let layer = context.add_layer();
+
+    let config = MenuBarConfig {
+        entries: vec![
+            MenuBarEntry {
+                title: String::from("File"),
+                items: vec![
+                    ContextMenuItem::new(Action::New, "New").with_shortcut("Ctrl+N"),
+                    ContextMenuItem::new(Action::Open, "Open").with_shortcut("Ctrl+O"),
+                    ContextMenuItem::new(Action::Save, "Save").with_shortcut("Ctrl+S"),
+                    //separators only divide the items into groups and can't be chosen
+                    ContextMenuItem::separator(Action::Separator),
+                    ContextMenuItem::new(Action::Quit, "Quit"),
+                ],
+            },
+            MenuBarEntry {
+                title: String::from("Edit"),
+                items: vec![
+                    ContextMenuItem::new(Action::Undo, "Undo").with_shortcut("Ctrl+Z"),
+                    ContextMenuItem::new(Action::Redo, "Redo")
+                        .with_shortcut("Ctrl+Y")
+                        .with_disabled(true),
+                ],
+            },
+            MenuBarEntry {
+                title: String::from("View"),
+                items: vec![
+                    //checkable items send a Toggled event with their new state
+                    ContextMenuItem::new(Action::ShowGrid, "Show grid").with_checked(true),
+                    ContextMenuItem::new(Action::Zoom(100), "Zoom").with_submenu(vec![
+                        ContextMenuItem::new(Action::Zoom(50), "50%"),
+                        ContextMenuItem::new(Action::Zoom(100), "100%"),
+                        ContextMenuItem::new(Action::Zoom(200), "200%"),
+                    ]),
+                ],
+            },
+        ],
+        location: Rectangle::new(Vector::ZERO, Vector::new(1366., 30.)),
+        title_width: 80.,
+        item_size: Vector::new(200., 30.),
+        font,
+        text_color: Color::BLACK,
+        background_color: Color::from_rgba(230, 230, 230, 1.0),
+        highlight_color: Color::from_rgba(150, 200, 255, 1.0),
+        border_color: Color::BLACK,
+    };
+    //the menus get their own layer on top of every other layer, so nothing added later covers them
+    let mut menu_bar = context
+        .add_menu_bar(config, &layer)
+        .expect("The layer was just added");
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        for event in menu_bar.channel.events() {
+            match event {
+                ContextMenuEvent::Selected(Action::Zoom(zoom)) => println!("Zoom set to {}%", zoom),
+                ContextMenuEvent::Selected(action) => println!("Chose {:?}", action),
+                ContextMenuEvent::Toggled(action, checked) => {
+                    println!("Turned {:?} {}", action, if checked { "on" } else { "off" })
+                }
+                ContextMenuEvent::Closed => println!("Closed the menu"),
+            }
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
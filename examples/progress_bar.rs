@@ -0,0 +1,162 @@
+//This example shows how to create progress bars, like health bars and loading bars
+
+use mergui::{
+    channels::ProgressBarChannel,
+    widgets::{
+        BarAnimation, FillDirection, NineSlice, ProgressBarConfig, ProgressBarLook, ProgressBarText,
+    },
+};
+use std::time::Duration;
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, Image, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Progress bar",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    let font = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 20.0)?,
+        location: Vector::new(5., 2.),
+        color: Color::WHITE,
+    };
+
+    //The value of a progress bar is stored in a channel, which we create ourselves.
+    //We can keep clones of it around to update the bar from wherever the value changes
+    let mut health = ProgressBarChannel::new(100.0, 100.0);
+    //A health bar that keeps showing the lost health in red for a moment after taking damage
+    let config = ProgressBarConfig {
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(300., 25.)),
+        channel: health.clone(),
+        look: ProgressBarLook::Colors {
+            background: Color::from_rgba(50, 50, 50, 1.0),
+            fill: Color::from_rgba(0, 160, 0, 1.0),
+        },
+        direction: FillDirection::LeftToRight,
+        //text is optional. The format function gets the value and the maximum
+        text: Some(ProgressBarText {
+            font_style: font.clone(),
+            format: Box::new(|value, max| format!("{}/{}", value, max)),
+        }),
+        animation: BarAnimation::DamageTrail {
+            color: Color::RED,
+            delay: Duration::from_millis(500),
+            //how much of the bar the trail shrinks per second
+            speed: 0.5,
+        },
+    };
+    let _health_bar = layer.add_widget(config);
+
+    //A loading bar using images. The borders of the image don't get stretched, see NineSlice
+    let button = Image::load(&gfx, "button.png").await?;
+    let mut loading = ProgressBarChannel::new(0.0, 100.0);
+    let config = ProgressBarConfig {
+        location: Rectangle::new(Vector::new(100., 100.), Vector::new(300., 40.)),
+        channel: loading.clone(),
+        look: ProgressBarLook::Images {
+            background: NineSlice {
+                image: button.clone(),
+                borders: (10.0, 10.0, 10.0, 10.0),
+            },
+            fill: NineSlice::stretched(button),
+        },
+        direction: FillDirection::LeftToRight,
+        text: Some(ProgressBarText {
+            font_style: FontStyle {
+                color: Color::BLACK,
+                location: Vector::new(10., 10.),
+                ..font
+            },
+            format: Box::new(|value, _| format!("Loading {}%", value.round())),
+        }),
+        //instead of jumping, the bar slides to the new value
+        animation: BarAnimation::Smooth { speed: 0.5 },
+    };
+    let _loading_bar = layer.add_widget(config);
+
+    //A mana bar that fills up from the bottom
+    let mut mana = ProgressBarChannel::new(0.0, 50.0);
+    let config = ProgressBarConfig {
+        location: Rectangle::new(Vector::new(450., 50.), Vector::new(25., 150.)),
+        channel: mana.clone(),
+        look: ProgressBarLook::Colors {
+            background: Color::from_rgba(50, 50, 50, 1.0),
+            fill: Color::BLUE,
+        },
+        direction: FillDirection::BottomToTop,
+        text: None,
+        animation: BarAnimation::None,
+    };
+    let _mana_bar = layer.add_widget(config);
+
+    //used to change the values of the bars over time
+    let mut damage_timer = Timer::time_per_second(1.0);
+    let mut progress_timer = Timer::time_per_second(4.0);
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the bars only show values, so we change them using the channels
+        if damage_timer.exhaust().is_some() {
+            if health.get() > 0.0 {
+                health.set(health.get() - 15.0);
+            } else {
+                health.set(health.get_max());
+            }
+        }
+        if progress_timer.exhaust().is_some() {
+            let next = (loading.get() + 10.0) % 110.0;
+            loading.set(next);
+            mana.set((mana.get() + 5.0) % mana.get_max());
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//This example shows how to create a radio group, where exactly one option is selected
+//It prints the selected difficulty whenever the selection changes
+
+use mergui::{
+    channels::RadioGroupEvent,
+    widgets::{RadioGroupConfig, RadioLayout},
+};
+
+use quicksilver::{
+    geom::Vector,
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+use std::marker::PhantomData;
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Radio group",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    let font_style = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 30.0)?,
+        location: Vector::new(100., 20.),
+        color: Color::BLACK,
+    };
+
+    //Now, we get to the radio group. A list of options where exactly one is selected
+    let config = RadioGroupConfig {
+        //the options. Just like with the dropdown, these can be anything that turns into a DropDownValueConfig
+        values: ["Easy", "Normal", "Hard", "Nightmare"]
+            .iter()
+            .map(|name| (name.to_string(), font_style.clone()))
+            .collect(),
+        //where the first option is drawn
+        location: Vector::new(100., 50.),
+        //how much space every option takes, including the button
+        option_size: Vector::new(200., 40.),
+        //place the options below each other
+        layout: RadioLayout::Vertical,
+        //the option that starts as selected
+        selected: 1,
+        border_color: Color::BLACK,
+        dot_color: Color::BLUE,
+        t: PhantomData,
+    };
+    let mut difficulty = layer.add_widget(config);
+
+    // Time to render the radio group
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the radio group tells us whenever the selection changed
+        for event in difficulty.channel.events() {
+            let RadioGroupEvent::Changed(difficulty) = event;
+            println!("Difficulty is now: {}", difficulty);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//This example shows how to create sliders and how to read their value
+
+use mergui::{
+    channels::SliderEvent,
+    widgets::{SliderConfig, SliderLook, SliderOrientation},
+};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::Context;
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Slider",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    //first, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //A slider, used to pick a number between a minimum and a maximum.
+    //It can be dragged, clicked on to jump to a value or changed with the arrow keys once it has focus
+    let config = SliderConfig {
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(300., 10.)),
+        min: 0.0,
+        max: 100.0,
+        //without a step, every value between min and max can be picked
+        step: None,
+        value: 50.0,
+        orientation: SliderOrientation::Horizontal,
+        handle_size: Vector::new(20., 30.),
+        look: SliderLook::Colors {
+            track: Color::from_rgba(200, 200, 200, 1.0),
+            fill: Color::BLUE,
+            handle: Color::BLACK,
+        },
+    };
+    let mut volume = layer.add_widget(config);
+
+    //This one can only go up by steps of 5 and goes from bottom to top
+    let config = SliderConfig {
+        location: Rectangle::new(Vector::new(500., 50.), Vector::new(10., 200.)),
+        min: 60.0,
+        max: 120.0,
+        step: Some(5.0),
+        value: 90.0,
+        orientation: SliderOrientation::Vertical,
+        handle_size: Vector::new(30., 20.),
+        look: SliderLook::Colors {
+            track: Color::from_rgba(200, 200, 200, 1.0),
+            fill: Color::GREEN,
+            handle: Color::BLACK,
+        },
+    };
+    let mut fov = layer.add_widget(config);
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the channels tell us when the user moved the sliders
+        for SliderEvent::Changed(value) in volume.channel.events() {
+            println!("Volume: {}", value);
+        }
+        for SliderEvent::Changed(value) in fov.channel.events() {
+            println!("FOV: {}", value);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
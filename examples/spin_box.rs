@@ -0,0 +1,119 @@
+//This example shows how to create spin boxes and how to read their value
+
+use mergui::{channels::SpinBoxEvent, widgets::SpinBoxConfig};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Spin box",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    let font = FontStyle {
+        font: MFont::from_font(&base_font, &gfx, 30.0)?,
+        location: Vector::new(5., 0.),
+        color: Color::BLACK,
+    };
+
+    //A spin box, used to type a number. It also has buttons to increase or decrease it
+    //The arrow keys work as well once it has focus.
+    let config = SpinBoxConfig {
+        font: font.clone(),
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(200., 40.)),
+        cursor_config: Default::default(),
+        //the type of the value decides if decimals can be used. This one only accepts whole numbers
+        value: 10i64,
+        min: 0,
+        max: 99,
+        //how much the buttons change the value
+        step: 1,
+        //integers don't have decimals, so this is ignored
+        precision: 0,
+        button_width: 30.0,
+        button_color: Color::from_rgba(200, 200, 200, 1.0),
+        arrow_color: Color::BLACK,
+        //no scrubbing for this one
+        scrub_speed: None,
+    };
+    let mut amount = layer.add_widget(config);
+
+    //This one uses decimals and can also be changed by dragging the mouse over it
+    let config = SpinBoxConfig {
+        font,
+        location: Rectangle::new(Vector::new(100., 120.), Vector::new(200., 40.)),
+        cursor_config: Default::default(),
+        value: 1.0f64,
+        min: 0.0,
+        max: 10.0,
+        step: 0.25,
+        //show 2 decimals
+        precision: 2,
+        button_width: 30.0,
+        button_color: Color::from_rgba(200, 200, 200, 1.0),
+        arrow_color: Color::BLACK,
+        //every pixel the mouse moves changes the value by 0.01
+        scrub_speed: Some(0.01),
+    };
+    let mut scale = layer.add_widget(config);
+
+    // Time to render the widgets
+    //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //the channels give back the numbers, instead of the text
+        for SpinBoxEvent::Changed(value) in amount.channel.events() {
+            println!("Amount: {}", value);
+        }
+        for SpinBoxEvent::Changed(value) in scale.channel.events() {
+            println!("Scale: {}", value);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//This example shows the tab view in action
+//it will show 3 tabs, two of them with a single button to change the background color.
+//Click on the tab view and use control+tab to switch tabs with the keyboard
+
+use mergui::widgets::{ButtonConfig, TabConfig, TabViewConfig};
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, Image, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Tab view",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+    //then, turn it into a MFont. This is done so we can clone the font, which is something we need.
+    let font = MFont::from_font(&base_font, &gfx, 15.0)?;
+
+    //we need an image for the tab headers and buttons
+    let button = Image::load(&gfx, "button.png").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //every tab gets its own layer. The widgets on it are only shown while the tab is open
+    let mut first_layer = context.add_singular_layer();
+    let mut to_cyan_button = first_layer.add_widget(ButtonConfig {
+        background: button.clone(),
+        background_location: Rectangle::new(Vector::new(100., 160.), Vector::new(100., 50.)),
+        blend_color: Some(Color::GREEN),
+        hover_color: Some(Color::RED),
+        font_style: FontStyle {
+            font: font.clone(),
+            location: Vector::new(20., 30.),
+            color: Color::WHITE,
+        },
+        text: "To Cyan".into(),
+    });
+    let second_layer = context.add_singular_layer();
+    let third_layer = context.add_singular_layer();
+
+    //now we make the tab view itself. It places the headers next to each other
+    let config = TabViewConfig {
+        tabs: vec![
+            TabConfig {
+                title: "First".into(),
+                layer: first_layer,
+            },
+            TabConfig {
+                title: "Second".into(),
+                layer: second_layer,
+            },
+            TabConfig {
+                title: "Third".into(),
+                layer: third_layer,
+            },
+        ],
+        location: Vector::new(100., 100.),
+        header_size: Vector::new(100., 50.),
+        background: button.clone(),
+        font_style: FontStyle {
+            font: font.clone(),
+            location: Vector::new(25., 30.), //relative to the header
+            color: Color::WHITE,
+        },
+        blend_color: Some(Color::from_rgba(100, 100, 100, 1.0)),
+        hover_color: Some(Color::RED),
+        //the header of the open tab is drawn in this color
+        active_color: Color::GREEN,
+        //the tab that starts open
+        active: 0,
+    };
+    let mut tabs = layer.add_widget(config);
+
+    //widgets can also be added to the tabs after creating the tab view, using its channel
+    let mut to_purple_button = tabs
+        .channel
+        .get_layer_mut(1)
+        .expect("There are 3 tabs")
+        .add_widget(ButtonConfig {
+            background: button,
+            background_location: Rectangle::new(Vector::new(100., 160.), Vector::new(100., 50.)),
+            blend_color: Some(Color::GREEN),
+            hover_color: Some(Color::RED),
+            font_style: FontStyle {
+                font,
+                location: Vector::new(20., 30.),
+                color: Color::WHITE,
+            },
+            text: "To Purple".into(),
+        });
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+    //this variable is used to change the background color when one of the hidden buttons gets clicked
+    let mut background_color = Color::WHITE;
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+
+            if to_cyan_button.channel.has_clicked() {
+                background_color = if background_color == Color::CYAN {
+                    Color::WHITE
+                } else {
+                    Color::CYAN
+                }
+            }
+            if to_purple_button.channel.has_clicked() {
+                background_color = if background_color == Color::PURPLE {
+                    Color::WHITE
+                } else {
+                    Color::PURPLE
+                }
+            }
+            //the channel tells which tabs got opened
+            for opened in tabs.channel.events() {
+                println!("Opened tab {}", opened);
+            }
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            //check if we need to draw a white_background or not
+            gfx.clear(background_color);
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
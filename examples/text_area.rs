@@ -0,0 +1,90 @@
+//This example shows how to create a multiline text area and how to interact with it
+//It does so by showing how many lines the user typed.
+
+use mergui::widgets::TextAreaConfig;
+
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, VectorFont},
+    Result, Timer, {run, Input, Settings, Window},
+};
+
+use mergui::{Context, FontStyle, MFont};
+
+fn main() {
+    run(
+        Settings {
+            size: Vector::new(1366., 768.),
+            title: "Text area",
+            resizable: false,
+            ..Settings::default()
+        },
+        app,
+    );
+}
+
+async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()> {
+    // Pretty much every widget needs a font. However we need to wrap it before we can use it.
+
+    //first, load the font like normal.
+    let base_font = VectorFont::load("font.ttf").await?;
+
+    //next up, we need to create the context for the GUI parts.
+    //The context is used for the following things
+    //1: Decide the render order.
+    //2: Decide what the cursor needs to be, based on its location
+    //3: Decide which widget currently has focus (if any)
+    //4: Decide which widget gets which events (if any).
+
+    let mut context = Context::new();
+
+    //we then construct a layer. A layer is used to group and control multiple widgets together.
+    let mut layer = context.add_layer();
+
+    //Now, we get to the text area. This works like a text input, except that the text can span multiple lines.
+    //Lines that are too long get wrapped and you can scroll through the text if it doesn't fit.
+    let text_area = layer.add_widget(TextAreaConfig {
+        font: FontStyle {
+            font: MFont::from_font(&base_font, &gfx, 30.0)?,
+            location: Vector::new(0., 0.),
+            color: Color::BLACK,
+        },
+        location: Rectangle::new(Vector::new(100., 50.), Vector::new(400., 200.)),
+        start_value: Some(String::from("Some text\nthat spans multiple lines")),
+        cursor_config: Default::default(),
+        validation: Default::default(),
+        //the color drawn behind the text that is selected using shift and the arrow keys
+        selection_color: Color::from_rgba(100, 150, 255, 0.5),
+    });
+
+    gfx.clear(Color::WHITE);
+    context.render(&mut gfx, &window)?;
+    gfx.present(&window)?;
+    //add a timer to make sure we draw at 60FPS and not faster.
+    let mut render_timer = Timer::time_per_second(60.0);
+
+    let example_font = MFont::from_font(&base_font, &gfx, 30.0)?;
+
+    loop {
+        while let Some(e) = inputs.next_event().await {
+            //whenever we are given an event, we pass it to context so it can update the widget.
+            context.event(&e, &window);
+        }
+        //limit the FPS to 60
+        if render_timer.exhaust().is_some() {
+            gfx.clear(Color::WHITE);
+
+            let line_count = text_area.channel.get().lines().count();
+            example_font.draw(
+                &mut gfx,
+                &format!("Lines: {}", line_count),
+                Color::BLACK,
+                Vector::new(100., 300.),
+            )?;
+            //render the widgets
+            context.render(&mut gfx, &window)?;
+            //paint to the screen
+            gfx.present(&window)?;
+        }
+    }
+}
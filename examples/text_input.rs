@@ -1,7 +1,8 @@
 //This example shows how to create a text input field and how to interact with it
 //It does so by rendering the inserted text somewhere else on the screen as well.
+//Once the user presses enter, the submitted text is also shown.
 
-use mergui::widgets::InputConfig;
+use mergui::{channels::InputEvent, widgets::InputConfig};
 
 use quicksilver::{
     geom::{Rectangle, Vector},
@@ -52,9 +53,12 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
         location: Rectangle::new(Vector::new(100., 50.), Vector::new(260., 50.)),
         start_value: Some(String::from("Text box")),
         cursor_config: Default::default(),
+        validation: Default::default(),
+        mask: None,
+        suggestions: None,
     };
     let config = var_name;
-    let text_input = layer.add_widget(config);
+    let mut text_input = layer.add_widget(config);
 
     // Time to render the button
     //First, render something to the screen. We do this out of the loop so we don't have to wait for the timers to draw the first frame.
@@ -65,12 +69,19 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
     let mut render_timer = Timer::time_per_second(60.0);
 
     let example_font = MFont::from_font(&base_font, &gfx, 30.0)?;
+    let mut submitted = String::new();
 
     loop {
         while let Some(e) = inputs.next_event().await {
             //whenever we are given an event, we pass it to context so it can update the widget.
             context.event(&e, &window);
         }
+        //the channel keeps track of everything the user did with the input.
+        for event in text_input.channel.events() {
+            if let InputEvent::Submitted(value) = event {
+                submitted = value;
+            }
+        }
         //limit the FPS to 60
         if render_timer.exhaust().is_some() {
             //check if we need to draw a white_background or not
@@ -82,6 +93,7 @@ async fn app(window: Window, mut gfx: Graphics, mut inputs: Input) -> Result<()>
                 Color::BLACK,
                 Vector::new(100., 135.),
             )?;
+            example_font.draw(&mut gfx, &submitted, Color::BLACK, Vector::new(100., 175.))?;
             //render the widgets
             context.render(&mut gfx, &window)?;
             //paint to the screen
@@ -0,0 +1,52 @@
+use super::ConcealerReturn;
+use crate::SingularLayerId;
+
+///Used to comunicate with an accordion. Every section has its own layer, which can be used to add widgets to the section
+pub struct AccordionChannel {
+    pub(crate) sections: Vec<ConcealerReturn>,
+    pub(crate) allow_multiple_open: bool,
+}
+
+impl AccordionChannel {
+    ///get if the given section is open
+    pub fn is_open(&self, id: usize) -> bool {
+        self.sections
+            .get(id)
+            .map(|section| section.layer.get_active())
+            .unwrap_or(false)
+    }
+    ///Opens or closes the given section.
+    ///If only one section can be open at a time, opening a section closes the others
+    pub fn set_open(&mut self, id: usize, open: bool) {
+        if id >= self.sections.len() {
+            return;
+        }
+        if open && !self.allow_multiple_open {
+            self.sections
+                .iter_mut()
+                .for_each(|section| section.layer.set_is_active(false));
+        }
+        self.sections[id].layer.set_is_active(open);
+    }
+    ///get the index of every open section
+    pub fn get_open(&self) -> Vec<usize> {
+        (0..self.sections.len())
+            .filter(|id| self.is_open(*id))
+            .collect()
+    }
+    ///get the amount of sections
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+    ///Gets the layer of a specific section
+    pub fn get_layer(&self, id: usize) -> Option<&SingularLayerId> {
+        self.sections.get(id).map(|v| &v.layer)
+    }
+    ///Gets the layer of a specific section as a mutable reference. Used to add widgets to the section
+    pub fn get_layer_mut(&mut self, id: usize) -> Option<&mut SingularLayerId> {
+        self.sections.get_mut(id).map(|v| &mut v.layer)
+    }
+}
@@ -0,0 +1,58 @@
+use std::{cell::RefCell, rc::Rc};
+
+///The state of a checkbox or toggle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    ///Neither checked or unchecked. For example, when only some of the options it stands for are checked.
+    ///The user can't set this, it can only be set from code
+    Indeterminate,
+}
+
+///Used to comunicate with checkboxes and toggles. It shares the state with the widget, so it can be read and changed at any time
+#[derive(Clone)]
+pub struct CheckboxChannel {
+    state: Rc<RefCell<CheckState>>,
+}
+
+impl CheckboxChannel {
+    pub fn new(state: CheckState) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(state)),
+        }
+    }
+    ///Returns true if it is checked. Indeterminate counts as not checked
+    pub fn is_checked(&self) -> bool {
+        self.get_state() == CheckState::Checked
+    }
+    ///Checks or unchecks it
+    pub fn set_checked(&mut self, checked: bool) {
+        self.set_state(if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        });
+    }
+    pub fn get_state(&self) -> CheckState {
+        *self.state.borrow()
+    }
+    pub fn set_state(&mut self, state: CheckState) {
+        self.state.replace(state);
+    }
+    ///Does the same as when the user clicks on it. Checked becomes unchecked, everything else becomes checked
+    ///
+    ///```
+    ///# use mergui::channels::{CheckboxChannel, CheckState};
+    ///let mut channel = CheckboxChannel::new(CheckState::Indeterminate);
+    ///channel.toggle();
+    ///assert!(channel.is_checked());
+    ///channel.toggle();
+    ///assert_eq!(channel.get_state(), CheckState::Unchecked);
+    ///```
+    pub fn toggle(&mut self) {
+        let checked = self.is_checked();
+        self.set_checked(!checked);
+    }
+}
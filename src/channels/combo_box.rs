@@ -0,0 +1,71 @@
+use super::{InputChannel, InputEvent};
+use std::{cell::RefCell, rc::Rc};
+
+///The value of a combo box
+#[derive(Clone, Debug, PartialEq)]
+pub enum ComboBoxValue<T: Clone> {
+    ///The user picked an option from the list
+    Selected(T),
+    ///The user typed something that isn't in the list
+    Typed(String),
+}
+
+///Used to comunicate with a combo box. It tells if the value got picked from the list or got typed by the user
+pub struct ComboBoxChannel<T: Clone> {
+    pub(crate) input: InputChannel,
+    pub(crate) options: Rc<RefCell<Vec<(T, String)>>>,
+    pub(crate) selected: Rc<RefCell<Option<usize>>>,
+    pub(crate) allow_free_text: bool,
+}
+
+impl<T: Clone> ComboBoxChannel<T> {
+    ///Get the current value.
+    ///
+    ///Returns None if nothing got picked and either nothing got typed or typed values aren't allowed
+    pub fn get_value(&self) -> Option<ComboBoxValue<T>> {
+        if let Some(selected) = self.get_selected() {
+            return self
+                .options
+                .borrow()
+                .get(selected)
+                .map(|(value, _)| ComboBoxValue::Selected(value.clone()));
+        }
+        let text = self.input.get();
+        if self.allow_free_text && !text.is_empty() {
+            Some(ComboBoxValue::Typed(text))
+        } else {
+            None
+        }
+    }
+    ///Get the index of the option that got picked, if any
+    pub fn get_selected(&self) -> Option<usize> {
+        *self.selected.borrow()
+    }
+    ///Picks the option at the given index and shows its text
+    pub fn set_selected(&mut self, index: usize) {
+        let text = match self.options.borrow().get(index) {
+            Some((_, text)) => text.clone(),
+            None => return,
+        };
+        self.input.set(text);
+        self.selected.replace(Some(index));
+    }
+    ///Get the text that is shown
+    pub fn get_text(&self) -> String {
+        self.input.get()
+    }
+    ///Changes the text that is shown. This counts as typed by the user
+    pub fn set_text(&mut self, text: String) {
+        self.input.set(text);
+        self.selected.replace(None);
+    }
+    ///Replaces every option and clears the selection
+    pub fn set_options(&mut self, options: Vec<(T, String)>) {
+        self.options.replace(options);
+        self.selected.replace(None);
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<InputEvent> {
+        self.input.events()
+    }
+}
@@ -1,19 +1,57 @@
 use super::{BasicClickable, ConcealerReturn};
 use crate::SingularLayerId;
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+///Something that changed in a concealer manager
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConcealerManagerEvent {
+    ///Another concealer became active. Contains its index, or None if every concealer got closed
+    Changed(Option<usize>),
+}
 
 pub struct ConcealerManagerReturn {
     channels: Vec<ConcealerReturn>,
     shown: Rc<RefCell<Option<usize>>>,
+    pub(crate) event_sender: Sender<ConcealerManagerEvent>,
+    events: Receiver<ConcealerManagerEvent>,
 }
 
 impl ConcealerManagerReturn {
     pub fn new(channels: Vec<ConcealerReturn>, shown: Rc<RefCell<Option<usize>>>) -> Self {
-        Self { channels, shown }
+        let (event_sender, events) = mpsc::channel();
+        Self {
+            channels,
+            shown,
+            event_sender,
+            events,
+        }
     }
-    ///set which concealer is active (if any)
+    ///set which concealer is active (if any).
+    ///This shows its layer and hides the layer of the concealer that was active before, the same as clicking on its button would
     pub fn set_active_concealer(&mut self, new_active: Option<usize>) {
-        self.shown.swap(&RefCell::new(new_active));
+        let new_active = new_active.filter(|index| *index < self.channels.len());
+        let old_active = self.shown.replace(new_active);
+        if old_active == new_active {
+            return;
+        }
+        if let Some(channel) = old_active.and_then(|index| self.channels.get_mut(index)) {
+            channel.layer.set_is_active(false);
+        }
+        if let Some(channel) = new_active.and_then(|index| self.channels.get_mut(index)) {
+            channel.layer.set_is_active(true);
+        }
+        let _ = self
+            .event_sender
+            .send(ConcealerManagerEvent::Changed(new_active));
+    }
+    ///Returns every change of the active concealer since the last time this function got called.
+    ///This includes changes made with set_active_concealer
+    pub fn events(&mut self) -> Vec<ConcealerManagerEvent> {
+        self.events.try_iter().collect()
     }
     ///get which concealer is currently active
     pub fn get_current_active(&self) -> Option<usize> {
@@ -0,0 +1,33 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver};
+
+///Something the user did with a context menu
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContextMenuEvent<T> {
+    ///The user chose an item. Contains its id. The menu closes afterwards
+    Selected(T),
+    ///The user chose a checkable item. Contains its id and if it is checked now. The menu closes afterwards
+    Toggled(T, bool),
+    ///The menu got closed without choosing an item
+    Closed,
+}
+
+///Used to comunicate with a context menu. See Context::open_context_menu
+pub struct ContextMenuChannel<T> {
+    pub(crate) is_open: Rc<RefCell<bool>>,
+    pub(crate) events: Receiver<ContextMenuEvent<T>>,
+}
+
+impl<T> ContextMenuChannel<T> {
+    ///get if the menu is still open
+    pub fn is_open(&self) -> bool {
+        *self.is_open.borrow()
+    }
+    ///Closes the menu without sending an event
+    pub fn close(&mut self) {
+        self.is_open.replace(false);
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<ContextMenuEvent<T>> {
+        self.events.try_iter().collect()
+    }
+}
@@ -1,10 +1,25 @@
 use crate::widgets::dropdown::DropDownValueConfig;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver};
+
+///The events that a dropdown sends when the user interacts with it
+#[derive(Clone, Debug, PartialEq)]
+pub enum DropdownEvent<T: Clone> {
+    ///The user selected a different option. Contains the new value
+    Changed(T),
+    ///The user opened the list
+    Opened,
+    ///The user closed the list
+    Closed,
+}
+
 ///Used to comunicate with the dropdown widget. It allows you to see if its open and to get the current selected value (if any)
+///
+///It can also be used to change the options and the selection while the dropdown is being shown.
 pub struct Dropdown<T: Clone> {
     pub(crate) is_open: Rc<RefCell<bool>>,
     pub(crate) selected: Rc<RefCell<Option<usize>>>,
     pub(crate) values: Rc<RefCell<Vec<DropDownValueConfig<T>>>>,
+    pub(crate) events: Receiver<DropdownEvent<T>>,
 }
 
 impl<T: Clone> Dropdown<T> {
@@ -19,4 +34,203 @@ impl<T: Clone> Dropdown<T> {
     pub fn is_open(&self) -> bool {
         *self.is_open.borrow()
     }
+    ///open or close the dropdown. When opened, the list scrolls to the selected option
+    pub fn set_open(&mut self, is_open: bool) {
+        self.is_open.replace(is_open);
+    }
+    ///get the index of the current selected option
+    pub fn get_selected(&self) -> Option<usize> {
+        *self.selected.borrow()
+    }
+    ///Selects the option at the given index.
+    ///Indexes outside of the list or of values that can't be selected clear the selection
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        let index = index.filter(|index| {
+            self.values
+                .borrow()
+                .get(*index)
+                .map(|value| value.is_selectable())
+                .unwrap_or(false)
+        });
+        self.selected.replace(index);
+    }
+    ///The amount of options
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+    ///Returns true if there are no options
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    ///Replaces every option. The selection is cleared if it no longer points to an option
+    pub fn set_values<V: Into<DropDownValueConfig<T>>>(&mut self, values: Vec<V>) {
+        self.values
+            .replace(values.into_iter().map(Into::into).collect());
+        let selected = self.get_selected();
+        self.set_selected(selected);
+    }
+    ///Adds an option to the end of the list
+    pub fn push_value<V: Into<DropDownValueConfig<T>>>(&mut self, value: V) {
+        self.values.borrow_mut().push(value.into());
+    }
+    ///Inserts an option at the given index. The selection keeps pointing to the same option
+    ///
+    ///Panics if index > len
+    ///
+    ///```no_run
+    ///# use mergui::{channels::Dropdown, widgets::DropDownValueConfig};
+    ///# fn example(mut channel: Dropdown<&str>, option: impl Fn(&'static str) -> DropDownValueConfig<&'static str>) {
+    ///channel.set_values(vec![option("a"), option("b")]);
+    ///channel.set_selected(Some(1));
+    ///channel.insert_value(0, option("c"));
+    ///assert_eq!(channel.get_selected(), Some(2));
+    ///assert_eq!(channel.get_value(), Some("b"));
+    ///# }
+    ///```
+    pub fn insert_value<V: Into<DropDownValueConfig<T>>>(&mut self, index: usize, value: V) {
+        self.values.borrow_mut().insert(index, value.into());
+        let selected = selected_after_insert(self.get_selected(), index);
+        self.selected.replace(selected);
+    }
+    ///Removes the option at the given index and returns it.
+    ///The selection gets cleared if the selected option got removed
+    ///
+    ///```no_run
+    ///# use mergui::{channels::Dropdown, widgets::DropDownValueConfig};
+    ///# fn example(mut channel: Dropdown<&str>, option: impl Fn(&'static str) -> DropDownValueConfig<&'static str>) {
+    ///channel.set_values(vec![option("a"), option("b"), option("c")]);
+    ///channel.set_selected(Some(2));
+    ///channel.remove_value(0);
+    ///assert_eq!(channel.get_value(), Some("c"));
+    ///channel.remove_value(1);
+    ///assert_eq!(channel.get_selected(), None);
+    ///# }
+    ///```
+    pub fn remove_value(&mut self, index: usize) -> Option<DropDownValueConfig<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let removed = self.values.borrow_mut().remove(index);
+        let selected = selected_after_remove(self.get_selected(), index);
+        self.selected.replace(selected);
+        Some(removed)
+    }
+    ///Replaces the option at the given index and returns the old one.
+    ///The selection is cleared if the selected option got replaced by one that can't be selected
+    ///
+    ///```no_run
+    ///# use mergui::{channels::Dropdown, widgets::DropDownValueConfig};
+    ///# fn example(mut channel: Dropdown<&str>, option: impl Fn(&'static str) -> DropDownValueConfig<&'static str>) {
+    ///# use mergui::widgets::DropDownEntryKind;
+    ///channel.set_values(vec![option("a"), option("b")]);
+    ///channel.set_selected(Some(1));
+    ///channel.replace_value(1, option("c"));
+    ///assert_eq!(channel.get_value(), Some("c"));
+    ///channel.replace_value(1, option("-").with_kind(DropDownEntryKind::Separator));
+    ///assert_eq!(channel.get_selected(), None);
+    ///# }
+    ///```
+    pub fn replace_value<V: Into<DropDownValueConfig<T>>>(
+        &mut self,
+        index: usize,
+        value: V,
+    ) -> Option<DropDownValueConfig<T>> {
+        let old = self
+            .values
+            .borrow_mut()
+            .get_mut(index)
+            .map(|old| std::mem::replace(old, value.into()));
+        let selected = self.get_selected();
+        self.set_selected(selected);
+        old
+    }
+    ///Swaps 2 options. The selection keeps pointing to the same option
+    ///
+    ///Panics if either index is out of bounds
+    ///
+    ///```no_run
+    ///# use mergui::{channels::Dropdown, widgets::DropDownValueConfig};
+    ///# fn example(mut channel: Dropdown<&str>, option: impl Fn(&'static str) -> DropDownValueConfig<&'static str>) {
+    ///channel.set_values(vec![option("a"), option("b"), option("c")]);
+    ///channel.set_selected(Some(0));
+    ///channel.swap_values(0, 2);
+    ///assert_eq!(channel.get_selected(), Some(2));
+    ///assert_eq!(channel.get_value(), Some("a"));
+    ///# }
+    ///```
+    pub fn swap_values(&mut self, a: usize, b: usize) {
+        self.values.borrow_mut().swap(a, b);
+        let selected = selected_after_swap(self.get_selected(), a, b);
+        self.selected.replace(selected);
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<DropdownEvent<T>> {
+        self.events.try_iter().collect()
+    }
+}
+
+///Where the selected option ends up after an option got inserted at the given index
+fn selected_after_insert(selected: Option<usize>, index: usize) -> Option<usize> {
+    selected.map(|selected| {
+        if selected >= index {
+            selected + 1
+        } else {
+            selected
+        }
+    })
+}
+
+///Where the selected option ends up after the option at the given index got removed
+fn selected_after_remove(selected: Option<usize>, index: usize) -> Option<usize> {
+    selected.and_then(|selected| {
+        if selected == index {
+            None
+        } else if selected > index {
+            Some(selected - 1)
+        } else {
+            Some(selected)
+        }
+    })
+}
+
+///Where the selected option ends up after 2 options got swapped
+fn selected_after_swap(selected: Option<usize>, a: usize, b: usize) -> Option<usize> {
+    selected.map(|selected| {
+        if selected == a {
+            b
+        } else if selected == b {
+            a
+        } else {
+            selected
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_keeps_the_selected_option() {
+        assert_eq!(selected_after_insert(Some(1), 0), Some(2));
+        assert_eq!(selected_after_insert(Some(1), 1), Some(2));
+        assert_eq!(selected_after_insert(Some(1), 2), Some(1));
+        assert_eq!(selected_after_insert(None, 0), None);
+    }
+
+    #[test]
+    fn remove_keeps_or_clears_the_selected_option() {
+        assert_eq!(selected_after_remove(Some(2), 0), Some(1));
+        assert_eq!(selected_after_remove(Some(2), 2), None);
+        assert_eq!(selected_after_remove(Some(2), 3), Some(2));
+        assert_eq!(selected_after_remove(None, 0), None);
+    }
+
+    #[test]
+    fn swap_keeps_the_selected_option() {
+        assert_eq!(selected_after_swap(Some(0), 0, 2), Some(2));
+        assert_eq!(selected_after_swap(Some(2), 0, 2), Some(0));
+        assert_eq!(selected_after_swap(Some(1), 0, 2), Some(1));
+        assert_eq!(selected_after_swap(Some(1), 1, 1), Some(1));
+    }
 }
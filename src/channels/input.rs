@@ -1,12 +1,62 @@
-use std::{cell::RefCell, rc::Rc};
+use crate::widgets::input::ValidationConfig;
+use std::{cell::RefCell, collections::VecDeque, ops::Range, rc::Rc};
 
 use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+///Something the user did with an input
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    ///The user changed the value. Multiple changes in a row are reported as a single event.
+    ///Use InputChannel::get to read the new value
+    Changed,
+    ///The user pressed enter. Contains the value at that moment
+    Submitted(String),
+    ///The input got focus
+    FocusGained,
+    ///The input lost focus
+    FocusLost,
+}
+
+///The amount of events that are kept if InputChannel::events doesn't get called. Older events are thrown away
+const MAX_QUEUED_EVENTS: usize = 64;
 
 #[derive(Clone)]
-pub struct InputChannel(Rc<RefCell<String>>);
+pub struct InputChannel {
+    value: Rc<RefCell<String>>,
+    validation: Rc<ValidationConfig>,
+    masked: Rc<RefCell<bool>>,
+    events: Rc<RefCell<VecDeque<InputEvent>>>,
+}
 impl InputChannel {
     pub fn new(v: String) -> Self {
-        let mut channel = InputChannel(Rc::new(RefCell::new(v)));
+        Self::with_validation(v, Default::default())
+    }
+
+    ///Creates a channel that only accepts the characters and values allowed by the given ValidationConfig
+    ///
+    ///```
+    /// # use mergui::{channels::InputChannel, widgets::input::ValidationConfig};
+    /// let mut channel = InputChannel::with_validation(
+    ///     "12".into(),
+    ///     ValidationConfig {
+    ///         filter: Some(Box::new(|c| c.is_ascii_digit())),
+    ///         max_length: Some(3),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// assert!(!channel.push('a'));
+    /// assert!(channel.push('3'));
+    /// assert!(!channel.push('4'));
+    /// assert_eq!(channel.get(), String::from("123"));
+    ///```
+    pub fn with_validation(v: String, validation: ValidationConfig) -> Self {
+        let mut channel = InputChannel {
+            value: Rc::new(RefCell::new(v)),
+            validation: Rc::new(validation),
+            masked: Rc::new(RefCell::new(false)),
+            events: Default::default(),
+        };
         channel.normalize();
         channel
     }
@@ -18,7 +68,7 @@ impl InputChannel {
     /// assert_eq!(channel.get(), String::from("abcd"));
     ///```
     pub fn get(&self) -> String {
-        self.0.borrow().clone()
+        self.value.borrow().clone()
     }
 
     ///Set a new value for the input.
@@ -31,49 +81,134 @@ impl InputChannel {
     /// assert_eq!(channel.get(), String::from("abcd"));
     ///```
     pub fn set(&mut self, new_val: String) {
-        self.0.replace(new_val);
+        self.value.replace(new_val);
         self.normalize();
     }
 
+    ///Set a new value, but only if every character passes the filter and it fits inside the max length.
+    ///Note: the string will be normalized before it is set to prevent issues when drawing
+    ///
+    ///Returns false and leaves the value untouched if the new value is rejected.
+    ///
+    ///```
+    ///# use mergui::{channels::InputChannel, widgets::input::ValidationConfig};
+    ///let mut channel = InputChannel::with_validation(
+    ///    "12".into(),
+    ///    ValidationConfig {
+    ///        filter: Some(Box::new(|c| c.is_ascii_digit())),
+    ///        max_length: Some(3),
+    ///        ..Default::default()
+    ///    },
+    ///);
+    ///assert!(!channel.try_set("12a".into()));
+    ///assert!(!channel.try_set("1234".into()));
+    ///assert!(channel.try_set("123".into()));
+    ///assert_eq!(channel.get(), String::from("123"));
+    ///```
+    pub fn try_set(&mut self, new_val: String) -> bool {
+        match self.check_insert(&new_val, new_val.clone()) {
+            Some(new_val) => {
+                self.value.replace(new_val);
+                true
+            }
+            None => false,
+        }
+    }
+
     ///Pushes a character to the end of this string. Then, normalize the value
     ///
+    ///Returns false and leaves the value untouched if the character is rejected by the filter or max length.
+    ///
     ///```
     ///# use mergui::channels::InputChannel;
     ///let mut channel = InputChannel::new("abc".into());
     ///channel.push('d');
     ///assert_eq!(channel.get(), String::from("abcd"));
     ///```
-    pub fn push(&mut self, to_add: char) {
-        let mut string = self.0.borrow_mut();
-        string.push(to_add);
-        drop(string);
-        self.normalize();
+    pub fn push(&mut self, to_add: char) -> bool {
+        let mut new_value = self.get();
+        new_value.push(to_add);
+        self.try_insert(to_add.encode_utf8(&mut [0; 4]), new_value)
     }
 
     /// Inserts a character at the given place. Then, normalize the value
     ///
+    ///Note: the place is counted in characters. Use InputChannel::insert_at_grapheme to count in what the user sees as characters.
+    ///
+    ///Returns false and leaves the value untouched if the character is rejected by the filter or max length.
+    ///
     ///```
     ///# use mergui::channels::InputChannel;
     ///let mut channel = InputChannel::new("abd".into());
     ///channel.insert_char_at_place(2,'c');
     ///assert_eq!(channel.get(), String::from("abcd"));
     ///```
-    pub fn insert_char_at_place(&mut self, char_index: usize, to_add: char) {
-        let mut old_str = self.0.borrow_mut();
-        let mut new_str = String::with_capacity(old_str.capacity());
-        for (key, value) in old_str.chars().enumerate() {
+    pub fn insert_char_at_place(&mut self, char_index: usize, to_add: char) -> bool {
+        let old_value = self.get();
+        let mut new_value = String::with_capacity(old_value.len() + to_add.len_utf8());
+        for (key, value) in old_value.chars().enumerate() {
             if key == char_index {
-                new_str.push(to_add);
+                new_value.push(to_add);
             }
-            new_str.push(value)
+            new_value.push(value)
         }
-        *old_str = new_str;
-        drop(old_str);
-        self.normalize();
+        self.try_insert(to_add.encode_utf8(&mut [0; 4]), new_value)
+    }
+
+    /// Inserts a character in front of the given grapheme. Then, normalize the value
+    ///
+    ///Returns false and leaves the value untouched if the character is rejected by the filter or max length.
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let mut channel = InputChannel::new("👍🏽b".into());
+    ///channel.insert_at_grapheme(1, 'a');
+    ///assert_eq!(channel.get(), String::from("👍🏽ab"));
+    ///
+    ///// combining characters become part of the grapheme in front of them
+    ///channel.insert_at_grapheme(2, '\u{301}');
+    ///assert_eq!(channel.get(), String::from("👍🏽áb"));
+    ///assert_eq!(channel.grapheme_count(), 3);
+    ///```
+    pub fn insert_at_grapheme(&mut self, grapheme_index: usize, to_add: char) -> bool {
+        self.insert_str_at_grapheme(grapheme_index, to_add.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Inserts text in front of the given grapheme. Then, normalize the value
+    ///
+    ///Returns false and leaves the value untouched if any of the characters is rejected by the filter
+    ///or if the whole text doesn't fit inside the max length. Text is never inserted partially.
+    ///
+    ///```
+    ///# use mergui::{channels::InputChannel, widgets::input::ValidationConfig};
+    ///let mut channel = InputChannel::with_validation(
+    ///    "ad".into(),
+    ///    ValidationConfig {
+    ///        max_length: Some(4),
+    ///        ..Default::default()
+    ///    },
+    ///);
+    ///assert!(channel.insert_str_at_grapheme(1, "bc"));
+    ///assert_eq!(channel.get(), String::from("abcd"));
+    ///assert!(!channel.insert_str_at_grapheme(4, "ef"));
+    ///assert_eq!(channel.get(), String::from("abcd"));
+    ///```
+    pub fn insert_str_at_grapheme(&mut self, grapheme_index: usize, to_add: &str) -> bool {
+        let old_value = self.get();
+        let byte_index = old_value
+            .grapheme_indices(true)
+            .nth(grapheme_index)
+            .map(|(index, _)| index)
+            .unwrap_or_else(|| old_value.len());
+        let mut new_value = old_value;
+        new_value.insert_str(byte_index, to_add);
+        self.try_insert(to_add, new_value)
     }
 
     /// Removes a character at the given place. Then, normalize the value
     ///
+    ///Note: the place is counted in characters. Use InputChannel::remove_grapheme_at to remove what the user sees as a character.
+    ///
     ///```
     ///# use mergui::channels::InputChannel;
     ///let mut channel = InputChannel::new("abdc".into());
@@ -82,17 +217,52 @@ impl InputChannel {
     ///```
     pub fn remove_char_at(&mut self, index: usize) {
         let new_value = self
-            .0
+            .value
             .borrow()
             .chars()
             .enumerate()
-            .map(|(key, character)| (key, character))
             .filter(|(key, _)| key != &index)
             .map(|(_, character)| character)
             .collect();
         self.set(new_value);
     }
 
+    /// Removes the grapheme at the given place. Then, normalize the value
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let mut channel = InputChannel::new("a🇳🇱q\u{301}👨‍👩‍👧".into());
+    ///channel.remove_grapheme_at(3);
+    ///assert_eq!(channel.get(), String::from("a🇳🇱q\u{301}"));
+    ///channel.remove_grapheme_at(2);
+    ///assert_eq!(channel.get(), String::from("a🇳🇱"));
+    ///channel.remove_grapheme_at(1);
+    ///assert_eq!(channel.get(), String::from("a"));
+    ///```
+    pub fn remove_grapheme_at(&mut self, index: usize) {
+        self.remove_graphemes(index..index + 1)
+    }
+
+    /// Removes every grapheme inside the given range. Then, normalize the value
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let mut channel = InputChannel::new("ab👍🏽q\u{308}zc".into());
+    ///channel.remove_graphemes(2..5);
+    ///assert_eq!(channel.get(), String::from("abc"));
+    ///```
+    pub fn remove_graphemes(&mut self, range: Range<usize>) {
+        let new_value = self
+            .value
+            .borrow()
+            .graphemes(true)
+            .enumerate()
+            .filter(|(key, _)| !range.contains(key))
+            .map(|(_, grapheme)| grapheme)
+            .collect();
+        self.set(new_value);
+    }
+
     ///Calculates the amount of characters inside a string.
     ///
     ///```
@@ -101,7 +271,35 @@ impl InputChannel {
     ///assert_eq!(channel.char_count(), 3);
     ///```
     pub fn char_count(&self) -> usize {
-        self.0.borrow().chars().count()
+        self.value.borrow().chars().count()
+    }
+
+    ///Calculates the amount of graphemes inside a string.
+    ///A grapheme is what the user sees as a single character, even if it exists of multiple characters.
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let channel = InputChannel::new("a👍🏽🇳🇱q\u{301}👨‍👩‍👧".into());
+    ///assert_eq!(channel.grapheme_count(), 5);
+    ///assert_eq!(channel.char_count(), 12);
+    ///```
+    pub fn grapheme_count(&self) -> usize {
+        self.value.borrow().graphemes(true).count()
+    }
+
+    ///Get every grapheme inside the string
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let channel = InputChannel::new("a👍🏽q\u{301}".into());
+    ///assert_eq!(channel.graphemes(), vec!["a", "👍🏽", "q\u{301}"]);
+    ///```
+    pub fn graphemes(&self) -> Vec<String> {
+        self.value
+            .borrow()
+            .graphemes(true)
+            .map(String::from)
+            .collect()
     }
 
     ///Get every character inside the string
@@ -112,11 +310,209 @@ impl InputChannel {
     ///assert_eq!(channel.chars(), vec!['a','b','c']);
     ///```
     pub fn chars(&self) -> Vec<char> {
-        self.0.borrow().chars().collect()
+        self.value.borrow().chars().collect()
+    }
+
+    ///Checks if the given character can be added to the current value.
+    ///It can't if the filter rejects it or if adding it to the end would make the value longer than its max length.
+    ///
+    ///Note: characters that combine with the grapheme in front of them can still be inserted once the max length is reached.
+    ///
+    ///```
+    ///# use mergui::{channels::InputChannel, widgets::input::ValidationConfig};
+    ///let channel = InputChannel::with_validation(
+    ///    "ab".into(),
+    ///    ValidationConfig {
+    ///        filter: Some(Box::new(|c| c.is_alphabetic())),
+    ///        ..Default::default()
+    ///    },
+    ///);
+    ///assert!(channel.can_insert('c'));
+    ///assert!(!channel.can_insert('1'));
+    ///
+    ///let channel = InputChannel::with_validation(
+    ///    "ab".into(),
+    ///    ValidationConfig {
+    ///        max_length: Some(2),
+    ///        ..Default::default()
+    ///    },
+    ///);
+    ///assert!(!channel.can_insert('c'));
+    ///assert!(channel.can_insert('\u{301}'));
+    ///```
+    pub fn can_insert(&self, to_add: char) -> bool {
+        let mut new_value = self.get();
+        new_value.push(to_add);
+        self.check_insert(to_add.encode_utf8(&mut [0; 4]), new_value)
+            .is_some()
+    }
+
+    ///Checks if the current value is valid.
+    ///A value is valid if every character passes the filter, it isn't longer than the max length and the validator accepts it.
+    ///
+    ///Values set through InputChannel::set are not blocked, so this can be used to check them.
+    ///
+    ///```
+    ///# use mergui::{channels::InputChannel, widgets::input::ValidationConfig};
+    ///let mut channel = InputChannel::with_validation(
+    ///    "8080".into(),
+    ///    ValidationConfig {
+    ///        validator: Some(Box::new(|v| v.parse::<u16>().is_ok())),
+    ///        ..Default::default()
+    ///    },
+    ///);
+    ///assert!(channel.is_valid());
+    ///channel.set("99999".into());
+    ///assert!(!channel.is_valid());
+    ///```
+    pub fn is_valid(&self) -> bool {
+        let value = self.value.borrow();
+        let chars_pass_filter = self
+            .validation
+            .filter
+            .as_ref()
+            .map(|filter| value.chars().all(filter))
+            .unwrap_or(true);
+        let fits = self
+            .validation
+            .max_length
+            .map(|max| value.graphemes(true).count() <= max)
+            .unwrap_or(true);
+        let passes_validator = self
+            .validation
+            .validator
+            .as_ref()
+            .map(|validator| validator(&value))
+            .unwrap_or(true);
+        chars_pass_filter && fits && passes_validator
+    }
+
+    ///Set if the value should be masked.
+    ///The value is only drawn masked if the input got a MaskConfig, but InputChannel::copy_text refuses to copy it either way
+    pub fn set_masked(&self, is_masked: bool) {
+        self.masked.replace(is_masked);
+    }
+
+    ///Get if the value is currently masked
+    pub fn is_masked(&self) -> bool {
+        *self.masked.borrow()
+    }
+
+    ///Get the value so it can be copied to the clipboard.
+    ///Returns None while the value is masked, as that would leak it.
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let channel = InputChannel::new("hunter2".into());
+    ///assert_eq!(channel.copy_text(), Some(String::from("hunter2")));
+    ///channel.set_masked(true);
+    ///assert_eq!(channel.copy_text(), None);
+    ///```
+    pub fn copy_text(&self) -> Option<String> {
+        if self.is_masked() {
+            None
+        } else {
+            Some(self.get())
+        }
+    }
+
+    ///Returns everything the user did with the input since the last time this function got called.
+    ///
+    ///Changing the value through the channel does not create events.
+    ///Only the last 64 events are kept, so nothing piles up if this never gets called.
+    ///
+    ///```
+    ///# use mergui::channels::InputChannel;
+    ///let mut channel = InputChannel::new("abc".into());
+    ///channel.set("abcd".into());
+    ///assert!(channel.events().is_empty());
+    ///```
+    pub fn events(&mut self) -> Vec<InputEvent> {
+        self.events.borrow_mut().drain(..).collect()
+    }
+
+    ///Returns true if the user pressed enter since the last time this function or InputChannel::events got called.
+    ///Only the Submitted events are removed, every other event can still be read with InputChannel::events
+    pub fn has_submitted(&mut self) -> bool {
+        let mut events = self.events.borrow_mut();
+        let old_len = events.len();
+        events.retain(|event| !matches!(event, InputEvent::Submitted(_)));
+        events.len() != old_len
+    }
+
+    pub(crate) fn send_event(&self, event: InputEvent) {
+        let mut events = self.events.borrow_mut();
+        if event == InputEvent::Changed && events.back() == Some(&InputEvent::Changed) {
+            return;
+        }
+        if events.len() == MAX_QUEUED_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    ///Sends a Changed event if the value is different from the given old value
+    pub(crate) fn send_if_changed(&self, old_value: &str) {
+        if *self.value.borrow() != old_value {
+            self.send_event(InputEvent::Changed);
+        }
+    }
+
+    ///Inserts text the user typed in front of the given grapheme and sends a single Changed event.
+    ///Returns where the cursor ends up, or None if the text got rejected
+    pub(crate) fn type_at_grapheme(
+        &mut self,
+        grapheme_index: usize,
+        to_add: &str,
+    ) -> Option<usize> {
+        let old_value = self.get();
+        let old_count = self.grapheme_count();
+        if !self.insert_str_at_grapheme(grapheme_index, to_add) {
+            return None;
+        }
+        self.send_if_changed(&old_value);
+        Some((grapheme_index + self.grapheme_count()).saturating_sub(old_count))
+    }
+
+    ///Sends a Submitted event with the current value
+    pub(crate) fn submit(&self) {
+        self.send_event(InputEvent::Submitted(self.get()));
+    }
+
+    ///Sets the new value if the inserted text passes the filter and the new value fits inside the max length
+    fn try_insert(&mut self, to_add: &str, new_value: String) -> bool {
+        match self.check_insert(to_add, new_value) {
+            Some(new_value) => {
+                self.value.replace(new_value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    ///Returns the normalized new value if every inserted character passes the filter and the new value fits inside the max length
+    fn check_insert(&self, to_add: &str, new_value: String) -> Option<String> {
+        let passes_filter = self
+            .validation
+            .filter
+            .as_ref()
+            .map(|filter| to_add.chars().all(filter))
+            .unwrap_or(true);
+        let new_value: String = new_value.nfc().collect();
+        let fits = self
+            .validation
+            .max_length
+            .map(|max| new_value.graphemes(true).count() <= max)
+            .unwrap_or(true);
+        if passes_filter && fits {
+            Some(new_value)
+        } else {
+            None
+        }
     }
 
     fn normalize(&mut self) {
-        self.0.replace_with(|v| v.nfc().collect());
+        self.value.replace_with(|v| v.nfc().collect());
     }
 }
 impl From<String> for InputChannel {
@@ -124,3 +520,75 @@ impl From<String> for InputChannel {
         Self::new(v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_sends_a_single_changed_event() {
+        let mut channel = InputChannel::new(String::new());
+        assert_eq!(channel.type_at_grapheme(0, "a"), Some(1));
+        assert_eq!(channel.type_at_grapheme(1, "b"), Some(2));
+        channel.submit();
+        assert_eq!(channel.type_at_grapheme(0, "c"), Some(1));
+        assert_eq!(
+            channel.events(),
+            vec![
+                InputEvent::Changed,
+                InputEvent::Submitted(String::from("ab")),
+                InputEvent::Changed,
+            ]
+        );
+        assert!(channel.events().is_empty());
+    }
+
+    #[test]
+    fn composed_text_is_inserted_at_once() {
+        let mut channel = InputChannel::with_validation(
+            String::from("a"),
+            ValidationConfig {
+                max_length: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(channel.type_at_grapheme(1, "bcd"), None);
+        assert_eq!(channel.get(), String::from("a"));
+        assert_eq!(channel.type_at_grapheme(0, "bc"), Some(2));
+        assert_eq!(channel.get(), String::from("bca"));
+        assert_eq!(channel.events(), vec![InputEvent::Changed]);
+    }
+
+    #[test]
+    fn rejected_characters_send_nothing() {
+        let mut channel = InputChannel::with_validation(
+            String::new(),
+            ValidationConfig {
+                filter: Some(Box::new(|c| c.is_ascii_digit())),
+                ..Default::default()
+            },
+        );
+        assert_eq!(channel.type_at_grapheme(0, "a"), None);
+        assert!(channel.events().is_empty());
+    }
+
+    #[test]
+    fn unread_events_are_capped() {
+        let mut channel = InputChannel::new(String::new());
+        for _ in 0..MAX_QUEUED_EVENTS * 2 {
+            channel.type_at_grapheme(0, "a");
+            channel.submit();
+        }
+        assert_eq!(channel.events().len(), MAX_QUEUED_EVENTS);
+    }
+
+    #[test]
+    fn has_submitted_keeps_other_events() {
+        let mut channel = InputChannel::new(String::new());
+        channel.type_at_grapheme(0, "a");
+        channel.submit();
+        assert!(channel.has_submitted());
+        assert!(!channel.has_submitted());
+        assert_eq!(channel.events(), vec![InputEvent::Changed]);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::widgets::dropdown::DropDownValueConfig;
+use std::{cell::RefCell, collections::BTreeSet, rc::Rc, sync::mpsc::Receiver};
+
+///The events that a list box sends when the user interacts with it
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListBoxEvent<T: Clone> {
+    ///The user changed the selection. Contains every selected value
+    Changed(Vec<T>),
+}
+
+///Used to comunicate with a list box. It gives back every selected value
+pub struct ListBoxChannel<T: Clone> {
+    pub(crate) selected: Rc<RefCell<BTreeSet<usize>>>,
+    pub(crate) values: Rc<RefCell<Vec<DropDownValueConfig<T>>>>,
+    ///Goes up every time the values get replaced, so the widget knows its keyboard position is outdated
+    pub(crate) values_version: Rc<RefCell<usize>>,
+    pub(crate) events: Receiver<ListBoxEvent<T>>,
+}
+
+impl<T: Clone> ListBoxChannel<T> {
+    ///get every selected value, in the order they are shown
+    pub fn get_values(&self) -> Vec<T> {
+        let values = self.values.borrow();
+        self.selected
+            .borrow()
+            .iter()
+            .filter_map(|index| values.get(*index))
+            .map(|value| value.value.clone())
+            .collect()
+    }
+    ///get the indexes of every selected value
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.selected.borrow().iter().copied().collect()
+    }
+    ///Returns true if the value at the given index is selected
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.borrow().contains(&index)
+    }
+    ///Replaces the selection. Indexes of values that don't exist or can't be selected are ignored
+    pub fn set_selected(&mut self, selected: Vec<usize>) {
+        let values = self.values.borrow();
+        self.selected.replace(
+            selected
+                .into_iter()
+                .filter(|index| {
+                    values
+                        .get(*index)
+                        .map(|value| value.is_selectable())
+                        .unwrap_or(false)
+                })
+                .collect(),
+        );
+    }
+    ///Replaces every value and clears the selection
+    pub fn set_values<V: Into<DropDownValueConfig<T>>>(&mut self, values: Vec<V>) {
+        self.values
+            .replace(values.into_iter().map(Into::into).collect());
+        self.selected.borrow_mut().clear();
+        *self.values_version.borrow_mut() += 1;
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<ListBoxEvent<T>> {
+        self.events.try_iter().collect()
+    }
+}
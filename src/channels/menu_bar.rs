@@ -0,0 +1,27 @@
+use super::ContextMenuEvent;
+use crate::LayerId;
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver};
+
+///Used to comunicate with a menu bar. The events of every menu come back through this channel
+pub struct MenuBarChannel<T> {
+    pub(crate) open: Rc<RefCell<Option<usize>>>,
+    pub(crate) events: Receiver<ContextMenuEvent<T>>,
+    ///The layer the menus are drawn on. It gets removed once the channel is dropped
+    pub(crate) _menus_layer: LayerId,
+}
+
+impl<T> MenuBarChannel<T> {
+    ///get which menu is open, if any
+    pub fn get_open(&self) -> Option<usize> {
+        *self.open.borrow()
+    }
+    ///Closes the open menu without sending an event
+    pub fn close(&mut self) {
+        self.open.replace(None);
+    }
+    ///Returns every event that happened since the last time this function got called.
+    ///A Closed event is send when the open menu got closed without choosing an item
+    pub fn events(&mut self) -> Vec<ContextMenuEvent<T>> {
+        self.events.try_iter().collect()
+    }
+}
@@ -1,12 +1,34 @@
+pub mod accordion;
+pub mod checkbox;
 pub mod clickable;
+pub mod combo_box;
 pub mod concealer;
 pub mod concealer_manager;
+pub mod context_menu;
 pub mod dropdown;
 pub mod input;
+pub mod list_box;
+pub mod menu_bar;
+pub mod progress_bar;
+pub mod radio_group;
+pub mod slider;
+pub mod spin_box;
+pub mod tab_view;
 
+pub use accordion::AccordionChannel;
+pub use checkbox::{CheckState, CheckboxChannel};
 pub use clickable::BasicClickable;
 pub use clickable::Clickable;
+pub use combo_box::{ComboBoxChannel, ComboBoxValue};
 pub use concealer::{Concealer, ConcealerReturn};
-pub use concealer_manager::ConcealerManagerReturn;
-pub use dropdown::Dropdown;
-pub use input::InputChannel;
+pub use concealer_manager::{ConcealerManagerEvent, ConcealerManagerReturn};
+pub use context_menu::{ContextMenuChannel, ContextMenuEvent};
+pub use dropdown::{Dropdown, DropdownEvent};
+pub use input::{InputChannel, InputEvent};
+pub use list_box::{ListBoxChannel, ListBoxEvent};
+pub use menu_bar::MenuBarChannel;
+pub use progress_bar::ProgressBarChannel;
+pub use radio_group::{RadioGroupChannel, RadioGroupEvent};
+pub use slider::{SliderChannel, SliderEvent};
+pub use spin_box::{SpinBoxChannel, SpinBoxEvent, SpinBoxNumber};
+pub use tab_view::TabViewChannel;
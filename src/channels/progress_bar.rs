@@ -0,0 +1,59 @@
+use std::{cell::RefCell, rc::Rc};
+
+///Used to comunicate with a progress bar. It shares the value with the widget, so every clone can change what the bar shows
+#[derive(Clone)]
+pub struct ProgressBarChannel {
+    value: Rc<RefCell<f32>>,
+    max: Rc<RefCell<f32>>,
+}
+
+impl ProgressBarChannel {
+    ///Creates a channel for a value between 0 and max
+    pub fn new(value: f32, max: f32) -> Self {
+        let channel = Self {
+            value: Rc::new(RefCell::new(0.0)),
+            max: Rc::new(RefCell::new(max.max(0.0))),
+        };
+        channel.value.replace(channel.clamp(value));
+        channel
+    }
+    pub fn get(&self) -> f32 {
+        *self.value.borrow()
+    }
+    ///Sets the value. It gets clamped between 0 and the maximum
+    ///
+    ///```
+    ///# use mergui::channels::ProgressBarChannel;
+    ///let mut health = ProgressBarChannel::new(100.0, 100.0);
+    ///health.set(25.0);
+    ///assert_eq!(health.fraction(), 0.25);
+    ///health.set(-10.0);
+    ///assert_eq!(health.get(), 0.0);
+    ///```
+    pub fn set(&mut self, value: f32) {
+        let value = self.clamp(value);
+        self.value.replace(value);
+    }
+    pub fn get_max(&self) -> f32 {
+        *self.max.borrow()
+    }
+    ///Sets the maximum. The value gets clamped to the new maximum
+    pub fn set_max(&mut self, max: f32) {
+        self.max.replace(max.max(0.0));
+        let value = self.get();
+        self.set(value);
+    }
+    ///Returns how full the bar is, from 0 to 1
+    pub fn fraction(&self) -> f32 {
+        let max = self.get_max();
+        if max > 0.0 {
+            self.get() / max
+        } else {
+            0.0
+        }
+    }
+
+    fn clamp(&self, value: f32) -> f32 {
+        value.clamp(0.0, self.get_max())
+    }
+}
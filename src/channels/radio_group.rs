@@ -0,0 +1,50 @@
+use crate::widgets::dropdown::DropDownValueConfig;
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Receiver};
+
+///The events that a radio group sends when the user interacts with it
+#[derive(Clone, Debug, PartialEq)]
+pub enum RadioGroupEvent<T: Clone> {
+    ///The user selected a different option. Contains the new value
+    Changed(T),
+}
+
+///Used to comunicate with a radio group. Exactly one option is selected at all times, unless none of them can be selected
+pub struct RadioGroupChannel<T: Clone> {
+    pub(crate) selected: Rc<RefCell<Option<usize>>>,
+    pub(crate) values: Rc<RefCell<Vec<DropDownValueConfig<T>>>>,
+    pub(crate) events: Receiver<RadioGroupEvent<T>>,
+}
+
+impl<T: Clone> RadioGroupChannel<T> {
+    ///get the selected value. Only returns None if none of the options can be selected
+    pub fn get_value(&self) -> Option<T> {
+        let selected = self.get_selected()?;
+        self.values
+            .borrow()
+            .get(selected)
+            .map(|value| value.value.clone())
+    }
+    ///get the index of the selected option. Only returns None if none of the options can be selected
+    pub fn get_selected(&self) -> Option<usize> {
+        *self.selected.borrow()
+    }
+    ///Selects the option at the given index.
+    ///
+    ///Returns false and keeps the current selection if the index is out of bounds or the option can't be selected
+    pub fn set_selected(&mut self, index: usize) -> bool {
+        let can_select = self
+            .values
+            .borrow()
+            .get(index)
+            .map(|value| value.is_selectable())
+            .unwrap_or(false);
+        if can_select {
+            self.selected.replace(Some(index));
+        }
+        can_select
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<RadioGroupEvent<T>> {
+        self.events.try_iter().collect()
+    }
+}
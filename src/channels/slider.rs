@@ -0,0 +1,97 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+///Something the user did with a slider
+#[derive(Clone, Debug, PartialEq)]
+pub enum SliderEvent {
+    ///The user moved the slider. Contains the new value
+    Changed(f32),
+}
+
+///Used to comunicate with a slider. It shares the value with the widget, so it can be read and changed at any time
+#[derive(Clone)]
+pub struct SliderChannel {
+    value: Rc<RefCell<f32>>,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+    event_sender: Sender<SliderEvent>,
+    events: Rc<Receiver<SliderEvent>>,
+}
+
+impl SliderChannel {
+    ///Creates a channel for a value between min and max.
+    ///If a step is given, the value always is min plus a multiple of step
+    pub fn new(value: f32, min: f32, max: f32, step: Option<f32>) -> Self {
+        let (event_sender, events) = mpsc::channel();
+        let channel = Self {
+            value: Rc::new(RefCell::new(min)),
+            min,
+            max: max.max(min),
+            step: step.filter(|step| *step > 0.0),
+            event_sender,
+            events: Rc::new(events),
+        };
+        channel.value.replace(channel.snap(value));
+        channel
+    }
+    pub fn get(&self) -> f32 {
+        *self.value.borrow()
+    }
+    ///Sets the value. It gets clamped between min and max and rounded to the nearest step
+    ///
+    ///```
+    ///# use mergui::channels::SliderChannel;
+    ///let mut channel = SliderChannel::new(0.0, 0.0, 10.0, Some(2.5));
+    ///channel.set(6.0);
+    ///assert_eq!(channel.get(), 5.0);
+    ///channel.set(20.0);
+    ///assert_eq!(channel.get(), 10.0);
+    ///```
+    pub fn set(&mut self, value: f32) {
+        let value = self.snap(value);
+        self.value.replace(value);
+    }
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+    pub fn step(&self) -> Option<f32> {
+        self.step
+    }
+    ///Returns how far the value is between min and max, from 0 to 1
+    pub fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            (self.get() - self.min) / (self.max - self.min)
+        } else {
+            0.0
+        }
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<SliderEvent> {
+        self.events.try_iter().collect()
+    }
+
+    ///Sets the value and sends a Changed event if it is different from the old one
+    pub(crate) fn set_by_user(&mut self, value: f32) {
+        let old = self.get();
+        self.set(value);
+        let new = self.get();
+        if new != old {
+            let _ = self.event_sender.send(SliderEvent::Changed(new));
+        }
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        match self.step {
+            Some(step) => (self.min + ((value - self.min) / step).round() * step).min(self.max),
+            None => value,
+        }
+    }
+}
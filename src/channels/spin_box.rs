@@ -0,0 +1,191 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+///A number that can be used by a spin box
+pub trait SpinBoxNumber: Copy + PartialOrd + 'static {
+    ///If true, only whole numbers can be typed and no decimals are shown
+    const IS_INTEGER: bool;
+    fn to_f64(self) -> f64;
+    ///Turns the number back. Integers are rounded
+    fn from_f64(value: f64) -> Self;
+    ///Turns typed text into a number. Returns None if it isn't one
+    fn from_text(text: &str) -> Option<Self>;
+    ///Turns the number into text with the given amount of decimals. Integers ignore the precision
+    fn to_text(self, precision: usize) -> String;
+    ///Adds step to the number the given amount of times
+    fn add_steps(self, step: Self, steps: i32) -> Self;
+}
+
+impl SpinBoxNumber for i64 {
+    const IS_INTEGER: bool = true;
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        value.round() as i64
+    }
+    fn from_text(text: &str) -> Option<Self> {
+        text.parse().ok()
+    }
+    fn to_text(self, _: usize) -> String {
+        self.to_string()
+    }
+    fn add_steps(self, step: Self, steps: i32) -> Self {
+        self.saturating_add(step.saturating_mul(steps.into()))
+    }
+}
+
+impl SpinBoxNumber for f64 {
+    const IS_INTEGER: bool = false;
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    fn from_text(text: &str) -> Option<Self> {
+        text.parse::<f64>().ok().filter(|value| value.is_finite())
+    }
+    fn to_text(self, precision: usize) -> String {
+        format!("{:.*}", precision, self)
+    }
+    fn add_steps(self, step: Self, steps: i32) -> Self {
+        self + step * f64::from(steps)
+    }
+}
+
+///Something the user did with a spin box
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpinBoxEvent<N: SpinBoxNumber> {
+    ///The user changed the number. Contains the new number
+    Changed(N),
+}
+
+///Used to comunicate with a spin box. It shares the number with the widget, so it can be read and changed at any time
+#[derive(Clone)]
+pub struct SpinBoxChannel<N: SpinBoxNumber> {
+    value: Rc<RefCell<N>>,
+    min: N,
+    max: N,
+    step: N,
+    precision: usize,
+    event_sender: Sender<SpinBoxEvent<N>>,
+    events: Rc<Receiver<SpinBoxEvent<N>>>,
+}
+
+impl<N: SpinBoxNumber> SpinBoxChannel<N> {
+    ///Creates a channel for a number between min and max. If max is smaller than min, it is set to min.
+    ///Precision is the amount of decimals that are shown and is ignored for integers
+    ///
+    ///```
+    ///# use mergui::channels::SpinBoxChannel;
+    ///let mut channel = SpinBoxChannel::new(5, 10, 0, 1, 0);
+    ///assert_eq!(channel.max(), 10);
+    ///assert_eq!(channel.get(), 10);
+    ///let mut channel = SpinBoxChannel::new(i64::MAX - 1, 0, i64::MAX, 1, 0);
+    ///channel.set(i64::MAX);
+    ///assert_eq!(channel.get(), i64::MAX);
+    ///let channel = SpinBoxChannel::new(2.0, f64::NAN, 1.0, 0.1, 1);
+    ///assert_eq!(channel.get(), 1.0);
+    ///```
+    pub fn new(value: N, min: N, max: N, step: N, precision: usize) -> Self {
+        let (event_sender, events) = mpsc::channel();
+        let channel = Self {
+            value: Rc::new(RefCell::new(min)),
+            min,
+            max: if max < min { min } else { max },
+            step,
+            precision: if N::IS_INTEGER { 0 } else { precision },
+            event_sender,
+            events: Rc::new(events),
+        };
+        channel.value.replace(channel.clean(value));
+        channel
+    }
+    pub fn get(&self) -> N {
+        *self.value.borrow()
+    }
+    ///Sets the number. It gets clamped between min and max and rounded to the precision
+    ///
+    ///```
+    ///# use mergui::channels::SpinBoxChannel;
+    ///let mut channel = SpinBoxChannel::new(0.0, -1.0, 1.0, 0.1, 2);
+    ///channel.set(0.1234);
+    ///assert_eq!(channel.get(), 0.12);
+    ///assert_eq!(channel.format(), "0.12");
+    ///channel.set(5.0);
+    ///assert_eq!(channel.get(), 1.0);
+    ///```
+    pub fn set(&mut self, value: N) {
+        let value = self.clean(value);
+        self.value.replace(value);
+    }
+    pub fn min(&self) -> N {
+        self.min
+    }
+    pub fn max(&self) -> N {
+        self.max
+    }
+    ///How much the number changes when the buttons or arrow keys are used
+    pub fn step(&self) -> N {
+        self.step
+    }
+    ///Turns the number into the text that is shown
+    ///
+    ///```
+    ///# use mergui::channels::SpinBoxChannel;
+    ///let channel = SpinBoxChannel::new(i64::MAX, 0, i64::MAX, 1, 0);
+    ///assert_eq!(channel.format(), "9223372036854775807");
+    ///assert_eq!(channel.parse(&channel.format()), Some(i64::MAX));
+    ///```
+    pub fn format(&self) -> String {
+        self.get().to_text(self.precision)
+    }
+    ///Turns text into a number, without clamping it
+    ///
+    ///```
+    ///# use mergui::channels::SpinBoxChannel;
+    ///let channel = SpinBoxChannel::new(0, 0, 100, 1, 0);
+    ///assert_eq!(channel.parse("-12"), Some(-12));
+    ///assert_eq!(channel.parse("1.5"), None);
+    ///```
+    pub fn parse(&self, text: &str) -> Option<N> {
+        N::from_text(text.trim())
+    }
+    ///Returns every event that happened since the last time this function got called
+    pub fn events(&mut self) -> Vec<SpinBoxEvent<N>> {
+        self.events.try_iter().collect()
+    }
+
+    ///Sets the number and sends a Changed event if it is different from the old one
+    pub(crate) fn set_by_user(&mut self, value: N) {
+        let old = self.get();
+        self.set(value);
+        let new = self.get();
+        if new != old {
+            let _ = self.event_sender.send(SpinBoxEvent::Changed(new));
+        }
+    }
+
+    ///Clamps the number between min and max and rounds it to the precision.
+    ///A bound that can't be compared, like NaN, doesn't limit the number
+    fn clean(&self, value: N) -> N {
+        let value = if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else if value.to_f64().is_nan() {
+            return self.get();
+        } else {
+            value
+        };
+        if N::IS_INTEGER {
+            return value;
+        }
+        let factor = 10f64.powi(self.precision as i32);
+        N::from_f64((value.to_f64() * factor).round() / factor)
+    }
+}
@@ -0,0 +1,46 @@
+use super::{ConcealerManagerEvent, ConcealerManagerReturn};
+use crate::SingularLayerId;
+
+///Used to comunicate with a tab view. Every tab has its own layer, which can be used to add widgets to the tab
+pub struct TabViewChannel {
+    pub(crate) manager: ConcealerManagerReturn,
+}
+
+impl TabViewChannel {
+    ///get which tab is currently open
+    pub fn get_active(&self) -> usize {
+        self.manager.get_current_active().unwrap_or(0)
+    }
+    ///Opens the given tab and closes the one that was open. Does nothing if the tab doesn't exist
+    pub fn set_active(&mut self, id: usize) {
+        if id < self.len() {
+            self.manager.set_active_concealer(Some(id));
+        }
+    }
+    ///Returns the index of every tab that got opened since the last time this function got called.
+    ///This includes tabs opened with set_active
+    pub fn events(&mut self) -> Vec<usize> {
+        self.manager
+            .events()
+            .into_iter()
+            .filter_map(|event| match event {
+                ConcealerManagerEvent::Changed(active) => active,
+            })
+            .collect()
+    }
+    ///get the amount of tabs
+    pub fn len(&self) -> usize {
+        self.manager.iter().count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    ///Gets the layer of a specific tab
+    pub fn get_layer(&self, id: usize) -> Option<&SingularLayerId> {
+        self.manager.get_layer(id)
+    }
+    ///Gets the layer of a specific tab as a mutable reference. Used to add widgets to the tab
+    pub fn get_layer_mut(&mut self, id: usize) -> Option<&mut SingularLayerId> {
+        self.manager.get_layer_mut(id)
+    }
+}
@@ -1,18 +1,27 @@
 use crate::{
-    widgets::{Widget, WidgetConfig},
+    channels::{ContextMenuChannel, MenuBarChannel},
+    widgets::{
+        CompositionEvent, ContextMenuConfig, GlobalClick, MenuBarConfig, Widget, WidgetConfig,
+    },
     LayerChannelReceiver, LayerChannelSender, LayerId, LayerInstructions, LayerNummerId, Response,
     SingularLayerId, WidgetChannelReceiver, WidgetChannelSender, WidgetId, WidgetNummerId,
 };
 use indexmap::IndexMap;
 use quicksilver::{
-    geom::Vector, graphics::Graphics, input::MouseButton, Result as QuickResult, Window,
+    geom::{Rectangle, Transform, Vector},
+    graphics::Graphics,
+    input::MouseButton,
+    Result as QuickResult, Window,
 };
 use std::{cell::RefCell, rc::Rc, sync::mpsc};
 
 struct Layer {
     is_active: Rc<RefCell<bool>>,
+    offset: Rc<RefCell<Vector>>,
     widgets: IndexMap<WidgetNummerId, Box<dyn Widget + 'static>>,
     current_id: Rc<RefCell<LayerNummerId>>,
+    ///Layers on top are drawn after and get events before every other layer
+    is_on_top: bool,
 }
 impl Default for Layer {
     fn default() -> Self {
@@ -23,8 +32,10 @@ impl Layer {
     pub fn new() -> Self {
         Self {
             is_active: Rc::new(RefCell::new(true)),
+            offset: Rc::new(RefCell::new(Vector::ZERO)),
             widgets: Default::default(),
             current_id: Rc::new(RefCell::new(0)),
+            is_on_top: false,
         }
     }
     pub fn get_mut(&mut self, index: u64) -> Option<&mut (dyn Widget + 'static)> {
@@ -42,6 +53,9 @@ impl Layer {
     pub(crate) fn is_active(&self) -> bool {
         *self.is_active.borrow()
     }
+    pub(crate) fn offset(&self) -> Vector {
+        *self.offset.borrow()
+    }
 }
 
 ///This manages the GUI. It contains every widget that needs to be drawn and makes sure they are updated properly
@@ -55,6 +69,7 @@ pub struct Context {
     widget_channel: WidgetChannelReceiver,
     widget_channel_creator: WidgetChannelSender,
     left_mouse_button_down: bool,
+    pressed_widget: Option<(u64, u64)>,
 }
 
 impl Default for Context {
@@ -77,6 +92,7 @@ impl Context {
             widget_channel: widget_rec,
             widget_channel_creator: widget_send,
             left_mouse_button_down: false,
+            pressed_widget: None,
         }
     }
 
@@ -85,18 +101,22 @@ impl Context {
     ///An example is Widgets::Concealer
     pub fn add_singular_layer(&mut self) -> SingularLayerId {
         let layer_id = self.add_layer();
-        SingularLayerId { 0: layer_id }
+        SingularLayerId(layer_id)
     }
 
     ///Adds a layer that can hold multiple widgets.
     ///Usefull to group widgets together that need to be removed at the same time
     pub fn add_layer(&mut self) -> LayerId {
+        self.insert_layer(Layer::new())
+    }
+
+    fn insert_layer(&mut self, layer: Layer) -> LayerId {
         self.last_layer_id += 1;
-        let layer = Layer::new();
         let id = LayerId::new(
             self.last_layer_id,
             self.layer_channel_creator.clone(),
             layer.is_active.clone(),
+            layer.offset.clone(),
             layer.current_id.clone(),
             self.widget_channel_creator.clone(),
         );
@@ -104,24 +124,68 @@ impl Context {
         id
     }
 
+    ///Opens a context menu on a new layer, on top of every layer. Layers added while it is open stay below it.
+    ///If the config has no location, the menu opens at the cursor. The menu gets focus, so it can be used with the keyboard.
+    ///
+    ///It closes itself once an item is chosen, the user clicks somewhere else or presses escape.
+    ///Dropping the response removes the menu.
+    pub fn open_context_menu<T: Clone + 'static>(
+        &mut self,
+        mut config: ContextMenuConfig<T>,
+    ) -> Response<ContextMenuChannel<T>> {
+        self.handle_extern_events();
+        let cursor = self.mouse_cursor;
+        config.location = config.location.or(Some(cursor));
+        let mut layer = Layer::new();
+        layer.is_on_top = true;
+        let layer = self.insert_layer(layer);
+        let response = self
+            .add_widget(config, &layer)
+            .expect("The layer was just added");
+        if let Some(focused) = self.get_focused_widget() {
+            focused.set_focus(cursor, false);
+        }
+        self.widget_with_focus = Some((response._id.layer, response._id.id));
+        response
+    }
+
     fn get_focused_widget(&mut self) -> Option<&mut (dyn Widget + 'static)> {
         self.widget_with_focus
             .and_then(move |v| self.to_display.get_mut(&v.0).and_then(|x| x.get_mut(v.1)))
     }
 
+    ///Gets the widget that got clicked on while the mouse button is still down, together with the offset of its layer
+    fn get_pressed_widget(&mut self) -> Option<(Vector, &mut (dyn Widget + 'static))> {
+        self.pressed_widget.and_then(move |v| {
+            self.to_display
+                .get_mut(&v.0)
+                .filter(|layer| layer.is_active())
+                .and_then(|x| {
+                    let offset = x.offset();
+                    x.get_mut(v.1).map(|widget| (offset, widget))
+                })
+        })
+    }
+
+    ///Gets every widget on an active layer, together with the offset of its layer
     fn get_widgets_mut<'b>(
         widgets: &'b mut IndexMap<u64, Layer>,
-    ) -> Vec<((u64, u64), &'b mut (dyn Widget + 'static))> {
-        widgets
+    ) -> Vec<((u64, u64), Vector, &'b mut (dyn Widget + 'static))> {
+        let mut layers: Vec<_> = widgets
             .iter_mut()
             .filter(|(_, layer)| layer.is_active())
+            .collect();
+        layers.sort_by_key(|(_, layer)| layer.is_on_top);
+        layers
+            .into_iter()
             .flat_map(|(layer_id, layer)| {
+                let offset = layer.offset();
                 layer
                     .widgets
                     .iter_mut()
-                    .map(move |(widget_id, widget)| ((layer_id, widget_id), widget))
+                    .map(move |(widget_id, widget)| ((layer_id, widget_id), offset, widget))
             })
-            .map(|(id, widget)| ((*id.0, *id.1), widget.as_mut()))
+            .map(|(id, offset, widget)| ((*id.0, *id.1), offset, widget.as_mut()))
             .collect()
     }
     fn handle_extern_events(&mut self) {
@@ -172,29 +236,34 @@ impl Context {
                 let mut widgets = Context::get_widgets_mut(&mut self.to_display);
                 let mut widgets = widgets
                     .iter_mut()
-                    .filter_map(|(_, widget)| {
-                        let does_hover = widget.contains(val);
+                    .filter_map(|(_, offset, widget)| {
+                        let does_hover = widget.contains(val - *offset);
                         if does_hover {
-                            Some(widget)
+                            Some((*offset, widget))
                         } else {
-                            widget.set_hover(cursor_location, false);
+                            widget.set_hover(cursor_location - *offset, false);
                             None
                         }
                     })
                     .collect::<Vec<_>>();
                 let cursor = widgets
                     .pop()
-                    .map(|widget| {
-                        widget.set_hover(cursor_location, true);
-                        widget.get_cursor_on_hover(cursor_location)
+                    .map(|(offset, widget)| {
+                        widget.set_hover(cursor_location - offset, true);
+                        widget.get_cursor_on_hover(cursor_location - offset)
                     })
                     .unwrap_or(quicksilver::CursorIcon::Default);
                 widgets
                     .iter_mut()
-                    .for_each(|v| v.set_hover(cursor_location, false));
+                    .for_each(|(offset, v)| v.set_hover(cursor_location - *offset, false));
 
                 window.set_cursor_icon(Some(cursor));
                 self.mouse_cursor = val;
+                if self.left_mouse_button_down {
+                    if let Some((offset, pressed)) = self.get_pressed_widget() {
+                        pressed.on_drag(val - offset)
+                    }
+                }
             }
             PointerInput(input) => {
                 if input.button() != MouseButton::Left {
@@ -206,6 +275,11 @@ impl Context {
                     //the button was released, we only have to mark it as such
                     (false, true) => {
                         self.left_mouse_button_down = false;
+                        let cursor = self.mouse_cursor;
+                        if let Some((offset, pressed)) = self.get_pressed_widget() {
+                            pressed.on_release(cursor - offset)
+                        }
+                        self.pressed_widget = None;
                         return;
                     }
                     //first time the button got pressed, mark it as such and continue prosessing the event
@@ -220,23 +294,23 @@ impl Context {
                 let mut widgets = Context::get_widgets_mut(&mut self.to_display);
                 let mut maybe_focused_widgets: Vec<_> = widgets
                     .iter_mut()
-                    .filter_map(|(id, widget)| {
+                    .filter_map(|(id, offset, widget)| {
+                        let cursor = cursor - *offset;
                         let contains = widget.contains(cursor);
                         let is_focusable = widget.is_focusable(cursor);
                         if contains {
-                            Some((id, widget, is_focusable))
+                            Some((id, cursor, widget, is_focusable))
                         } else {
                             widget.set_focus(cursor, false);
                             None
                         }
                     })
                     .collect();
-                let cursor = self.mouse_cursor;
                 let current_focused_id = self.widget_with_focus;
                 self.widget_with_focus =
                     maybe_focused_widgets
                         .pop()
-                        .map(|(id, widget, is_focusable)| {
+                        .map(|(id, cursor, widget, is_focusable)| {
                             if is_focusable
                                 && current_focused_id
                                     .map(|(layer, widget)| id.0 != layer || id.1 != widget)
@@ -247,13 +321,24 @@ impl Context {
                             widget.on_click(cursor);
                             (id.0, id.1)
                         });
+                self.pressed_widget = self.widget_with_focus;
                 maybe_focused_widgets
                     .iter_mut()
-                    .for_each(|(_, widget, is_focusable)| {
+                    .for_each(|(_, cursor, widget, is_focusable)| {
                         if *is_focusable {
-                            widget.set_focus(cursor, false)
+                            widget.set_focus(*cursor, false)
                         }
                     });
+                let layer = self.widget_with_focus.map(|(layer, _)| layer);
+                let cursor = self.mouse_cursor;
+                Context::get_widgets_mut(&mut self.to_display)
+                    .iter_mut()
+                    .for_each(|(_, offset, widget)| {
+                        widget.on_global_click(&GlobalClick {
+                            location: cursor - *offset,
+                            layer,
+                        })
+                    });
             }
             KeyboardInput(event) => {
                 let key = event.key();
@@ -261,23 +346,74 @@ impl Context {
                 if let Some(focused) = self.get_focused_widget() {
                     focused.on_key_press(key, is_down)
                 }
+                Context::get_widgets_mut(&mut self.to_display)
+                    .iter_mut()
+                    .for_each(|(_, _, widget)| widget.on_global_key_press(key, is_down));
             }
             ReceivedCharacter(typed) => {
                 if let Some(v) = self.get_focused_widget() {
                     v.on_typed(typed.character())
                 }
             }
+            ScrollInput(delta) => {
+                let cursor = self.mouse_cursor;
+                let mut widgets = Context::get_widgets_mut(&mut self.to_display);
+                if let Some((_, _, widget)) = widgets
+                    .iter_mut()
+                    .filter(|(_, offset, widget)| widget.contains(cursor - *offset))
+                    .last()
+                {
+                    widget.on_scroll(delta)
+                }
+            }
             _ => {}
         }
     }
+    ///Passes a text composition event from an input method to the widget that has focus.
+    ///
+    ///Quicksilver doesn't report these events itself, so they need to come from the platform.
+    pub fn composition(&mut self, event: CompositionEvent) {
+        self.handle_extern_events();
+        if let Some(focused) = self.get_focused_widget() {
+            focused.on_composition(&event)
+        }
+    }
+    ///Get where the text cursor of the focused widget is drawn, if it has one.
+    ///
+    ///Can be used to position the candidate window of an input method.
+    pub fn get_caret_rect(&self) -> Option<Rectangle> {
+        self.widget_with_focus.and_then(|(layer, widget)| {
+            self.to_display
+                .get(&layer)
+                .filter(|layer| layer.is_active())
+                .and_then(|layer| {
+                    let offset = layer.offset();
+                    layer
+                        .widgets
+                        .get(&widget)
+                        .and_then(|widget| widget.get_caret_rect())
+                        .map(|rect| Rectangle::new(rect.pos + offset, rect.size))
+                })
+        })
+    }
     ///Call this in the render function of your state to render every widget
+    ///
+    ///Widgets on a layer with an offset are drawn with a translated transform.
+    ///Afterwards the transform is set to `Transform::IDENTITY`, not to whatever it was before.
+    ///If you use your own transform and a layer has an offset, set your transform again after calling this
     pub fn render(&mut self, gfx: &mut Graphics, window: &Window) -> QuickResult<()> {
         self.handle_extern_events();
         let mut widgets = Context::get_widgets_mut(&mut self.to_display);
-        widgets
-            .iter_mut()
-            .map(|(_, widget)| widget.render(gfx, window))
-            .collect()
+        widgets.iter_mut().try_for_each(|(_, offset, widget)| {
+            widget.set_layer_offset(*offset);
+            if *offset == Vector::ZERO {
+                return widget.render(gfx, window);
+            }
+            gfx.set_transform(Transform::translate(*offset));
+            let res = widget.render(gfx, window);
+            gfx.set_transform(Transform::IDENTITY);
+            res
+        })
     }
     ///Adds a widget configuration to a given layer.
     ///
@@ -285,6 +421,7 @@ impl Context {
     ///
     ///Otherwise, returns a channel to comunicate with the new widget.
     ///Note: You can also add a widget using LayerId::add_widget.
+    #[allow(clippy::result_unit_err)]
     pub fn add_widget<R, W, Res>(
         &mut self,
         widget: R,
@@ -294,20 +431,61 @@ impl Context {
         R: WidgetConfig<Res, W>,
         W: Widget + 'static,
         Res: Sized,
+    {
+        if !self.to_display.contains_key(&layer_id.id()) {
+            return Err(());
+        }
+        let (widget, res) = widget.to_widget();
+        self.insert_widget(widget, res, layer_id)
+    }
+
+    ///Adds a menu bar to the given layer.
+    ///
+    ///Its menus are drawn on a new layer that stays on top of every other layer, so widgets added later can't cover them.
+    ///That layer is shown, hidden and moved together with the given layer and gets removed once the response is dropped.
+    ///
+    ///Returns an Error if the layer does not exist.
+    #[allow(clippy::result_unit_err)]
+    pub fn add_menu_bar<T: Clone + 'static>(
+        &mut self,
+        config: MenuBarConfig<T>,
+        layer_id: &LayerId,
+    ) -> Result<Response<MenuBarChannel<T>>, ()> {
+        if !self.to_display.contains_key(&layer_id.id()) {
+            return Err(());
+        }
+        let mut menus_layer = Layer::new();
+        menus_layer.is_active = layer_id.is_active.clone();
+        menus_layer.offset = layer_id.offset.clone();
+        menus_layer.is_on_top = true;
+        let menus_layer = self.insert_layer(menus_layer);
+        let menus_layer_id = menus_layer.id();
+        let (bar, menus, channel) = config.into_widgets(menus_layer);
+        if let Some(layer) = self.to_display.get_mut(&menus_layer_id) {
+            layer.insert(Box::new(menus));
+        }
+        self.insert_widget(bar, channel, layer_id)
+    }
+
+    fn insert_widget<W, Res>(
+        &mut self,
+        widget: W,
+        res: Res,
+        layer_id: &LayerId,
+    ) -> Result<Response<Res>, ()>
+    where
+        W: Widget + 'static,
     {
         match self.to_display.get_mut(&layer_id.id()) {
-            Some(layer) => {
-                let (widget, res) = widget.to_widget();
-                Ok(Response {
-                    _layer_id: layer_id.clone(),
-                    channel: res,
-                    _id: WidgetId::new(
-                        layer_id.id(),
-                        layer.insert(Box::new(widget)),
-                        self.widget_channel_creator.clone(),
-                    ),
-                })
-            }
+            Some(layer) => Ok(Response {
+                _layer_id: layer_id.clone(),
+                channel: res,
+                _id: WidgetId::new(
+                    layer_id.id(),
+                    layer.insert(Box::new(widget)),
+                    self.widget_channel_creator.clone(),
+                ),
+            }),
             _ => Err(()),
         }
     }
@@ -58,6 +58,52 @@ impl MFont {
             .layout_glyphs(gfx, text, max_width, callback)
     }
 
+    ///The distance between the top of two lines of text
+    pub fn line_height(&self, gfx: &mut Graphics) -> Result<f32> {
+        //Laying out an empty string results in a single empty line.
+        Ok(self.layout_glyphs(gfx, "", None, |_, _| {})?.y)
+    }
+
+    ///Calculates where a cursor is drawn in front of every character of a single line of text.
+    ///
+    ///The returned list contains one more entry than the amount of characters, the last one being the end of the text.
+    pub(crate) fn caret_offsets(&self, gfx: &mut Graphics, text: &str) -> Result<Vec<f32>> {
+        let space_width = self.space_width(gfx)?;
+        let mut glyph_edges = Vec::new();
+        self.layout_glyphs(gfx, text, None, |_, glyph| {
+            glyph_edges.push((
+                glyph.position.x,
+                glyph.position.x + glyph.glyph.bounds.width as f32,
+            ))
+        })?;
+        //whitespace doesn't produce glyphs, so every glyph belongs to the next non whitespace character
+        let mut glyph_edges = glyph_edges.into_iter();
+        let mut offsets = Vec::with_capacity(text.len() + 1);
+        let mut end = 0.0;
+        for character in text.chars() {
+            if character.is_whitespace() {
+                offsets.push(end);
+                end += space_width;
+            } else if let Some((left, right)) = glyph_edges.next() {
+                offsets.push(left);
+                end = right;
+            } else {
+                offsets.push(end);
+            }
+        }
+        offsets.push(end);
+        Ok(offsets)
+    }
+
+    fn space_width(&self, gfx: &mut Graphics) -> Result<f32> {
+        let mut glyph_x = Vec::with_capacity(4);
+        self.layout_glyphs(gfx, "ii i", None, |_, glyph| glyph_x.push(glyph.position.x))?;
+        Ok(match glyph_x.as_slice() {
+            [first, second, third] => (third - second) - (second - first),
+            _ => self.size / 4.0,
+        })
+    }
+
     ///similair to FontRenderer::draw
     pub fn draw(
         &self,
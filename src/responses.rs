@@ -1,4 +1,5 @@
 use crate::widgets::{Widget, WidgetConfig};
+use quicksilver::geom::Vector;
 use std::{
     cell::RefCell,
     rc::Rc,
@@ -47,6 +48,10 @@ impl SingularLayerId {
     pub fn get_active(&self) -> bool {
         self.0.get_active()
     }
+    ///Get how far the widgets on this layer are moved from where they were placed
+    pub fn get_offset(&self) -> Vector {
+        self.0.get_offset()
+    }
 
     pub(crate) fn set_is_active(&mut self, is_active: bool) {
         self.0.set_is_active(is_active)
@@ -59,6 +64,7 @@ impl SingularLayerId {
 pub struct LayerId {
     layer: Rc<InternalLayerId>,
     pub(crate) is_active: Rc<RefCell<bool>>,
+    pub(crate) offset: Rc<RefCell<Vector>>,
     widget_id: Rc<RefCell<WidgetNummerId>>,
     widget_channel: WidgetChannelSender,
 }
@@ -68,6 +74,7 @@ impl LayerId {
         id: LayerNummerId,
         channel: LayerChannelSender,
         is_active: Rc<RefCell<bool>>,
+        offset: Rc<RefCell<Vector>>,
         widget_id: Rc<RefCell<WidgetNummerId>>,
         widget_channel: WidgetChannelSender,
     ) -> Self {
@@ -75,6 +82,7 @@ impl LayerId {
         Self {
             layer,
             is_active,
+            offset,
             widget_id,
             widget_channel,
         }
@@ -112,6 +120,18 @@ impl LayerId {
     pub fn get_active(&self) -> bool {
         *self.is_active.borrow()
     }
+
+    ///Moves every widget on this layer by the given offset, without changing their configuration.
+    ///The widgets are drawn using a translated transform, which gets set to `Transform::IDENTITY` afterwards.
+    ///Layers without an offset don't touch the transform. See `Context::render`
+    pub fn set_offset(&self, offset: Vector) {
+        self.offset.replace(offset);
+    }
+
+    ///Get how far the widgets on this layer are moved from where they were placed
+    pub fn get_offset(&self) -> Vector {
+        *self.offset.borrow()
+    }
 }
 pub(crate) struct InternalLayerId {
     pub(crate) id: LayerNummerId,
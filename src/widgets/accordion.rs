@@ -0,0 +1,180 @@
+use super::{concealer::Concealer, ButtonConfig, ConcealerConfig, Widget, WidgetConfig};
+use crate::{channels::AccordionChannel, FontStyle, SingularLayerId};
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, Image},
+    Result, Window,
+};
+use std::{cell::RefCell, rc::Rc};
+
+///A single section of an accordion
+pub struct AccordionSection {
+    ///The text shown on the header of the section
+    pub title: String,
+    ///The layer that is shown below the header while the section is open.
+    ///Place its widgets as if the section is directly below its header while every section above it is closed.
+    ///The layer gets moved down when sections above it are opened.
+    ///The accordion sets the offset of this layer every frame, so changes made with `set_offset` get overwritten
+    pub layer: SingularLayerId,
+    ///How much space the content of the section needs. Every section below it moves down by this much when it is opened
+    pub content_height: f32,
+}
+
+///Configures an accordion. A stack of headers where clicking on one opens its section below it, moving the sections below it down
+pub struct AccordionConfig {
+    pub sections: Vec<AccordionSection>,
+    ///Where the first header is drawn
+    pub location: Vector,
+    ///The size of every header
+    pub header_size: Vector,
+    ///The image drawn as the background of every header
+    pub background: Image,
+    ///The style of the titles. The location is relative to the header
+    pub font_style: FontStyle,
+    ///optionally, the color that the headers of closed sections blend with
+    pub blend_color: Option<Color>,
+    ///optionally, the color the header blends with if the user hovers over it
+    pub hover_color: Option<Color>,
+    ///The color that the headers of open sections blend with
+    pub open_color: Color,
+    ///If false, opening a section closes the others
+    pub allow_multiple_open: bool,
+    ///The sections that start open. If multiple sections aren't allowed, only the first one is opened
+    pub open: Vec<usize>,
+}
+
+pub struct Accordion {
+    sections: Vec<Concealer>,
+    offsets: Vec<Rc<RefCell<Vector>>>,
+    content_heights: Vec<f32>,
+    pub location: Vector,
+    pub header_size: Vector,
+    pub blend_color: Option<Color>,
+    pub open_color: Color,
+    pub allow_multiple_open: bool,
+}
+
+impl WidgetConfig<AccordionChannel, Accordion> for AccordionConfig {
+    fn to_widget(self) -> (Accordion, AccordionChannel) {
+        let font_style = self.font_style;
+        let background = self.background;
+        let blend_color = self.blend_color;
+        let hover_color = self.hover_color;
+        let header_size = self.header_size;
+        let mut sections = Vec::new();
+        let mut channels = Vec::new();
+        let mut offsets = Vec::new();
+        let mut content_heights = Vec::new();
+        for section in self.sections {
+            offsets.push(section.layer.0.offset.clone());
+            content_heights.push(section.content_height);
+            let (widget, channel) = ConcealerConfig {
+                button: ButtonConfig {
+                    text: section.title,
+                    font_style: font_style.clone(),
+                    background: background.clone(),
+                    background_location: Rectangle::new(Vector::ZERO, header_size),
+                    blend_color,
+                    hover_color,
+                },
+                layer: section.layer,
+                is_popover: false,
+                open_on_hover: None,
+            }
+            .to_widget();
+            sections.push(widget);
+            channels.push(channel);
+        }
+        let mut channel = AccordionChannel {
+            sections: channels,
+            allow_multiple_open: self.allow_multiple_open,
+        };
+        for id in self.open {
+            if self.allow_multiple_open || channel.get_open().is_empty() {
+                channel.set_open(id, true);
+            }
+        }
+        let mut accordion = Accordion {
+            sections,
+            offsets,
+            content_heights,
+            location: self.location,
+            header_size,
+            blend_color,
+            open_color: self.open_color,
+            allow_multiple_open: self.allow_multiple_open,
+        };
+        accordion.layout();
+        (accordion, channel)
+    }
+}
+
+impl Accordion {
+    ///Places every header below the previous one and moves the layers of the sections to match
+    fn layout(&mut self) {
+        let mut moved_down = 0.0;
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            let header = Vector::new(
+                self.location.x,
+                self.location.y + self.header_size.y * index as f32 + moved_down,
+            );
+            section.button.set_position(header);
+            self.offsets[index].replace(Vector::new(0., moved_down));
+            if *section.is_active.borrow() {
+                moved_down += self.content_heights[index];
+            }
+        }
+    }
+    fn get_section_index(&self, pos: Vector) -> Option<usize> {
+        self.sections
+            .iter()
+            .position(|section| section.button.contains(pos))
+    }
+}
+
+impl Widget for Accordion {
+    fn contains(&self, pos: Vector) -> bool {
+        self.get_section_index(pos).is_some()
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        false
+    }
+    fn render(&mut self, gfx: &mut Graphics, w: &Window) -> Result<()> {
+        //sections can also be opened or closed through the channel, so the layout gets updated every frame
+        self.layout();
+        let blend_color = self.blend_color;
+        let open_color = self.open_color;
+        for section in &mut self.sections {
+            section.button.background.button.color = if *section.is_active.borrow() {
+                Some(open_color)
+            } else {
+                blend_color
+            };
+            section.render(gfx, w)?;
+        }
+        Ok(())
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+    fn set_hover(&mut self, pos: Vector, state: bool) {
+        for section in &mut self.sections {
+            let hover = state && section.contains(pos);
+            section.set_hover(pos, hover);
+        }
+    }
+    fn on_click(&mut self, pos: Vector) {
+        let index = match self.get_section_index(pos) {
+            Some(index) => index,
+            None => return,
+        };
+        let opens = !*self.sections[index].is_active.borrow();
+        if opens && !self.allow_multiple_open {
+            for section in &mut self.sections {
+                section.set_is_concealing(false);
+            }
+        }
+        self.sections[index].on_click(pos);
+        self.layout();
+    }
+}
@@ -62,6 +62,15 @@ impl WidgetConfig<Clickable, Button> for ButtonConfig {
     }
 }
 
+impl Button {
+    ///Moves the button and its text so its top left corner is at the given position
+    pub(crate) fn set_position(&mut self, pos: Vector) {
+        let moved = pos - self.background.button.location.pos;
+        self.background.button.location.pos = pos;
+        self.text.button.font_style.location += moved;
+    }
+}
+
 impl Widget for Button {
     fn contains(&self, point: Vector) -> bool {
         self.background.contains(point) || self.text.contains(point)
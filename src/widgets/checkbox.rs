@@ -0,0 +1,157 @@
+use super::{Widget, WidgetConfig};
+use crate::{
+    channels::{CheckState, CheckboxChannel},
+    FontStyle,
+};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics, Image},
+    input::Key,
+    Result, Window,
+};
+
+///Text drawn next to a checkbox or toggle. Clicking on it works the same as clicking on the widget
+#[derive(Clone)]
+pub struct LabelConfig {
+    pub text: String,
+    ///The location of the font is relative to the location of the widget
+    pub font_style: FontStyle,
+}
+
+///Draws the label and remembers where it got drawn, so clicks on it can be detected
+pub(crate) struct Label {
+    config: LabelConfig,
+    area: Option<Rectangle>,
+}
+
+impl Label {
+    pub(crate) fn new(mut config: LabelConfig, location: Rectangle) -> Self {
+        config.font_style.location += location.pos;
+        Self { config, area: None }
+    }
+    pub(crate) fn contains(&self, point: Vector) -> bool {
+        self.area.map(|area| area.contains(point)).unwrap_or(false)
+    }
+    pub(crate) fn render(&mut self, gfx: &mut Graphics) -> Result<()> {
+        let style = &self.config.font_style;
+        let size = style.draw(gfx, &self.config.text)?;
+        let mut top_left = style.location;
+        top_left.y -= style.font.size;
+        self.area = Some(Rectangle::new(top_left, size));
+        Ok(())
+    }
+}
+
+///How a checkbox looks
+#[derive(Clone)]
+pub enum CheckboxLook {
+    ///Draws an image for every state
+    Images {
+        unchecked: Image,
+        checked: Image,
+        indeterminate: Image,
+    },
+    ///Draws a square. A filled square is drawn inside it when checked and a bar when indeterminate
+    Drawn {
+        border_color: Color,
+        check_color: Color,
+    },
+}
+
+///Configures a checkbox
+pub struct CheckboxConfig {
+    ///Where the box is drawn
+    pub location: Rectangle,
+    pub look: CheckboxLook,
+    ///Optionally, text that is drawn next to the box
+    pub label: Option<LabelConfig>,
+    ///The state it starts in
+    pub state: CheckState,
+}
+
+pub struct Checkbox {
+    pub location: Rectangle,
+    pub look: CheckboxLook,
+    label: Option<Label>,
+    channel: CheckboxChannel,
+}
+
+impl WidgetConfig<CheckboxChannel, Checkbox> for CheckboxConfig {
+    fn to_widget(self) -> (Checkbox, CheckboxChannel) {
+        let channel = CheckboxChannel::new(self.state);
+        let location = self.location;
+        (
+            Checkbox {
+                location,
+                look: self.look,
+                label: self.label.map(|label| Label::new(label, location)),
+                channel: channel.clone(),
+            },
+            channel,
+        )
+    }
+}
+
+impl Widget for Checkbox {
+    fn contains(&self, point: Vector) -> bool {
+        self.location.contains(point)
+            || self
+                .label
+                .as_ref()
+                .map(|label| label.contains(point))
+                .unwrap_or(false)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let state = self.channel.get_state();
+        match &self.look {
+            CheckboxLook::Images {
+                unchecked,
+                checked,
+                indeterminate,
+            } => {
+                let image = match state {
+                    CheckState::Unchecked => unchecked,
+                    CheckState::Checked => checked,
+                    CheckState::Indeterminate => indeterminate,
+                };
+                gfx.draw_image(image, self.location);
+            }
+            CheckboxLook::Drawn {
+                border_color,
+                check_color,
+            } => {
+                gfx.stroke_rect(&self.location, *border_color);
+                let mut mark = self.location;
+                mark.pos += mark.size / 4.0;
+                mark.size /= 2.0;
+                match state {
+                    CheckState::Unchecked => {}
+                    CheckState::Checked => gfx.fill_rect(&mark, *check_color),
+                    CheckState::Indeterminate => {
+                        mark.pos.y += mark.size.y / 3.0;
+                        mark.size.y /= 3.0;
+                        gfx.fill_rect(&mark, *check_color)
+                    }
+                }
+            }
+        }
+        if let Some(label) = &mut self.label {
+            label.render(gfx)?;
+        }
+        Ok(())
+    }
+    fn on_click(&mut self, _: Vector) {
+        self.channel.toggle();
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if key == Key::Space && state {
+            self.channel.toggle();
+        }
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
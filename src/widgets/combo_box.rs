@@ -0,0 +1,247 @@
+use crate::{
+    channels::{ComboBoxChannel, InputChannel},
+    widgets::{
+        input::{Input, PlaceholderConfig, SuggestionConfig},
+        CompositionEvent, CursorConfig, InputConfig, Widget, WidgetConfig,
+    },
+    FontStyle,
+};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::Key,
+    Result, Window,
+};
+use std::{cell::RefCell, rc::Rc};
+
+///Decides which options are shown for the text the user typed. Both ignore case
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComboBoxFilter {
+    ///Shows the options that contain the typed text
+    Substring,
+    ///Shows the options that contain every typed character in the same order
+    Fuzzy,
+}
+
+impl ComboBoxFilter {
+    ///Returns if the option should be shown for the typed text
+    ///
+    ///```
+    ///# use mergui::widgets::combo_box::ComboBoxFilter;
+    ///assert!(ComboBoxFilter::Substring.matches("lan", "Netherlands"));
+    ///assert!(!ComboBoxFilter::Substring.matches("nl", "Netherlands"));
+    ///assert!(ComboBoxFilter::Fuzzy.matches("nl", "Netherlands"));
+    ///assert!(!ComboBoxFilter::Fuzzy.matches("ln", "Nl"));
+    ///```
+    pub fn matches(&self, typed: &str, option: &str) -> bool {
+        let typed = typed.to_lowercase();
+        let option = option.to_lowercase();
+        match self {
+            ComboBoxFilter::Substring => option.contains(&typed),
+            ComboBoxFilter::Fuzzy => {
+                let mut option = option.chars();
+                typed
+                    .chars()
+                    .all(|typed| option.any(|character| character == typed))
+            }
+        }
+    }
+}
+
+///Configures a combo box. A text field where the user can either type or pick from a filtered list of options
+pub struct ComboBoxConfig<T: Clone> {
+    pub font: FontStyle,
+    pub placeholder: Option<PlaceholderConfig>,
+    pub location: Rectangle,
+    pub cursor_config: CursorConfig,
+    ///The values that can be picked and the text that is shown for them
+    pub options: Vec<(T, String)>,
+    ///The option that is picked at the start
+    pub selected: Option<usize>,
+    ///How the options are filtered while typing
+    pub filter: ComboBoxFilter,
+    ///If the user may enter text that isn't one of the options.
+    ///If not, the text is cleared when it doesn't match an option once the user is done typing
+    pub allow_free_text: bool,
+    ///The height of every option inside the list
+    pub option_height: f32,
+    ///The maximum amount of options that are shown at once
+    pub max_shown: usize,
+    ///The color behind every option
+    pub background_color: Color,
+    ///The color behind the option that is highlighted or hovered over
+    pub highlight_color: Color,
+    ///The color of the line between and arround every option
+    pub divider_color: Color,
+}
+
+pub struct ComboBox<T: Clone> {
+    input: Input,
+    location: Rectangle,
+    channel: ComboBoxChannel<T>,
+    ///The index of the option behind every suggestion that the input shows
+    shown: Rc<RefCell<Vec<usize>>>,
+    ///Only losing focus after having it commits the typed text
+    has_focus: bool,
+}
+
+impl<T: Clone + 'static> WidgetConfig<ComboBoxChannel<T>, ComboBox<T>> for ComboBoxConfig<T> {
+    fn to_widget(self) -> (ComboBox<T>, ComboBoxChannel<T>) {
+        let selected = self
+            .selected
+            .filter(|selected| *selected < self.options.len());
+        let start_value = selected.map(|selected| self.options[selected].1.clone());
+        let options = Rc::new(RefCell::new(self.options));
+        let selected = Rc::new(RefCell::new(selected));
+        let filter = self.filter;
+        let allow_free_text = self.allow_free_text;
+        let location = self.location;
+        let provider_options = options.clone();
+        let shown = Rc::new(RefCell::new(Vec::new()));
+        let provider_shown = shown.clone();
+        let (input, input_channel) = InputConfig {
+            font: self.font,
+            placeholder: self.placeholder,
+            location: self.location,
+            start_value,
+            cursor_config: self.cursor_config,
+            validation: Default::default(),
+            mask: None,
+            suggestions: Some(SuggestionConfig {
+                provider: Box::new(move |typed| {
+                    let options = provider_options.borrow();
+                    let matching: Vec<usize> = (0..options.len())
+                        .filter(|index| filter.matches(typed, &options[*index].1))
+                        .collect();
+                    let texts = matching
+                        .iter()
+                        .map(|index| options[*index].1.clone())
+                        .collect();
+                    provider_shown.replace(matching);
+                    texts
+                }),
+                option_height: self.option_height,
+                max_shown: self.max_shown,
+                background_color: self.background_color,
+                highlight_color: self.highlight_color,
+                divider_color: self.divider_color,
+            }),
+        }
+        .to_widget();
+        let new_channel = |input: InputChannel| ComboBoxChannel {
+            input,
+            options: options.clone(),
+            selected: selected.clone(),
+            allow_free_text,
+        };
+        (
+            ComboBox {
+                input,
+                location,
+                channel: new_channel(InputChannel::clone(&input_channel)),
+                shown,
+                has_focus: false,
+            },
+            new_channel(input_channel),
+        )
+    }
+}
+
+impl<T: Clone> ComboBox<T> {
+    ///Updates the selection after the input handled an event
+    fn sync(&mut self, old_text: &str) {
+        if let Some(accepted) = self.input.take_accepted_suggestion() {
+            let index = self.shown.borrow().get(accepted).copied();
+            self.channel.selected.replace(index);
+        } else if self.channel.get_text() != old_text {
+            self.channel.selected.replace(None);
+        }
+    }
+    ///Called once the user is done typing. Picks the option that matches the typed text,
+    ///or clears the text if it doesn't match anything and free text isn't allowed
+    fn commit_typed(&mut self) {
+        if self.channel.get_selected().is_some() {
+            return;
+        }
+        let old_text = self.channel.get_text();
+        let lowercase = old_text.to_lowercase();
+        let found = self
+            .channel
+            .options
+            .borrow()
+            .iter()
+            .position(|(_, text)| text.to_lowercase() == lowercase);
+        match found {
+            Some(found) => self.channel.set_selected(found),
+            None if !self.channel.allow_free_text => self.channel.input.set(String::new()),
+            None => {}
+        }
+        self.channel.input.send_if_changed(&old_text);
+    }
+}
+
+impl<T: Clone> Widget for ComboBox<T> {
+    fn contains(&self, pos: Vector) -> bool {
+        self.input.contains(pos)
+    }
+    fn is_focusable(&self, pos: Vector) -> bool {
+        self.input.is_focusable(pos)
+    }
+    fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
+        self.input.render(gfx, window)
+    }
+    fn get_cursor_on_hover(&self, pos: Vector) -> quicksilver::CursorIcon {
+        self.input.get_cursor_on_hover(pos)
+    }
+    fn set_focus(&mut self, pos: Vector, focus: bool) {
+        self.input.set_focus(pos, focus);
+        if !focus && self.has_focus {
+            self.commit_typed();
+        }
+        self.has_focus = focus;
+    }
+    fn set_hover(&mut self, pos: Vector, state: bool) {
+        self.input.set_hover(pos, state)
+    }
+    fn on_click(&mut self, pos: Vector) {
+        let was_open = self.input.has_suggestions();
+        let old_text = self.channel.get_text();
+        self.input.on_click(pos);
+        self.sync(&old_text);
+        //clicking on the field itself opens or closes the list
+        if self.location.contains(pos) {
+            if was_open {
+                self.input.close_suggestions();
+            } else {
+                self.input.update_suggestions();
+            }
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if key == Key::Down && state && !self.input.has_suggestions() {
+            self.input.update_suggestions();
+            return;
+        }
+        //the typed text is committed before the input sends its Submitted event, so the event has the final value
+        let is_enter = (key == Key::Return || key == Key::NumpadEnter) && state;
+        if is_enter && !self.input.has_highlighted_suggestion() {
+            self.commit_typed();
+        }
+        let old_text = self.channel.get_text();
+        self.input.on_key_press(key, state);
+        self.sync(&old_text);
+    }
+    fn on_typed(&mut self, typed: char) {
+        let old_text = self.channel.get_text();
+        self.input.on_typed(typed);
+        self.sync(&old_text);
+    }
+    fn on_composition(&mut self, event: &CompositionEvent) {
+        let old_text = self.channel.get_text();
+        self.input.on_composition(event);
+        self.sync(&old_text);
+    }
+    fn get_caret_rect(&self) -> Option<Rectangle> {
+        self.input.get_caret_rect()
+    }
+}
@@ -1,20 +1,32 @@
-use super::{button::Button, ButtonConfig, Widget, WidgetConfig};
-use crate::{channels::concealer::ConcealerReturn, SingularLayerId};
+use super::{button::Button, ButtonConfig, GlobalClick, Widget, WidgetConfig};
+use crate::{channels::concealer::ConcealerReturn, LayerNummerId, SingularLayerId};
 use quicksilver::graphics::Graphics;
-use quicksilver::{geom::Vector, Result, Window};
+use quicksilver::{geom::Vector, input::Key, Result, Timer, Window};
 
 //use quicksilver::prelude::{Vector, Window};
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc, time::Duration};
 ///A button that will hide/unhide other widgets when the user clicks on it
 pub struct ConcealerConfig {
     ///A button that when clicked will hide/unhide the other widgets
     pub button: ButtonConfig,
     pub layer: SingularLayerId,
+    ///If true, it also hides the widgets when the user clicks somewhere that isn't the button or one of the hidden widgets, or presses escape.
+    ///Usefull for popups and "more options" menus
+    pub is_popover: bool,
+    ///If set, the widgets are also shown when the mouse hovers over the button for this long
+    pub open_on_hover: Option<Duration>,
 }
 
 pub struct Concealer {
     pub button: Button,
     pub is_active: Rc<RefCell<bool>>,
+    pub is_popover: bool,
+    pub open_on_hover: Option<Duration>,
+    layer_id: LayerNummerId,
+    ///Started when the mouse starts hovering over the button, if it opens on hover
+    hover_timer: Option<Timer>,
+    ///The timer only starts again once the mouse left the button
+    is_hovered: bool,
 }
 
 impl WidgetConfig<ConcealerReturn, Concealer> for ConcealerConfig {
@@ -27,6 +39,11 @@ impl WidgetConfig<ConcealerReturn, Concealer> for ConcealerConfig {
             Concealer {
                 is_active: Rc::clone(&is_concealing),
                 button: main_button_widget,
+                is_popover: self.is_popover,
+                open_on_hover: self.open_on_hover,
+                layer_id: self.layer.0.id(),
+                hover_timer: None,
+                is_hovered: false,
             },
             ConcealerReturn {
                 layer: self.layer,
@@ -44,19 +61,39 @@ impl Widget for Concealer {
     }
     fn set_hover(&mut self, location: Vector, hover: bool) {
         self.button.set_hover(location, hover);
+        let started_hovering = hover && !self.is_hovered;
+        self.is_hovered = hover;
+        if !hover {
+            self.hover_timer = None;
+        } else if started_hovering {
+            self.hover_timer = self.open_on_hover.map(Timer::with_duration);
+        }
     }
     fn render(&mut self, gfx: &mut Graphics, w: &Window) -> Result<()> {
+        if self.take_hover_open() {
+            self.set_is_concealing(true);
+        }
         self.button.render(gfx, w)?;
         Ok(())
     }
     fn on_click(&mut self, clicked_on: Vector) {
         let current_state = self.is_active();
         self.set_is_concealing(!current_state);
-        self.button.on_click(clicked_on);
+        self.click_button(clicked_on);
     }
     fn get_cursor_on_hover(&self, pos: Vector) -> quicksilver::CursorIcon {
         self.button.get_cursor_on_hover(pos)
     }
+    fn on_global_click(&mut self, click: &GlobalClick) {
+        if self.closes_on_click(click) {
+            self.set_is_concealing(false);
+        }
+    }
+    fn on_global_key_press(&mut self, key: Key, state: bool) {
+        if self.closes_on_key_press(key, state) {
+            self.set_is_concealing(false);
+        }
+    }
 }
 
 impl Concealer {
@@ -66,4 +103,32 @@ impl Concealer {
     pub fn set_is_concealing(&mut self, state: bool) {
         self.is_active.swap(&RefCell::new(state));
     }
+    ///Passes the click to the button. Clicking decides the state until the mouse leaves the button
+    pub(crate) fn click_button(&mut self, clicked_on: Vector) {
+        self.hover_timer = None;
+        self.button.on_click(clicked_on);
+    }
+    ///Returns true once the mouse hovered over the button long enough to open it
+    pub(crate) fn take_hover_open(&mut self) -> bool {
+        let hovered_long_enough = self
+            .hover_timer
+            .as_ref()
+            .map(|timer| timer.remaining().is_none())
+            .unwrap_or(false);
+        if hovered_long_enough {
+            self.hover_timer = None;
+        }
+        hovered_long_enough
+    }
+    ///Returns true if this is a shown popover and the click is outside of it
+    pub(crate) fn closes_on_click(&self, click: &GlobalClick) -> bool {
+        self.is_popover
+            && self.is_active()
+            && !self.button.contains(click.location)
+            && click.layer != Some(self.layer_id)
+    }
+    ///Returns true if this is a popover and the key closes it
+    pub(crate) fn closes_on_key_press(&self, key: Key, state: bool) -> bool {
+        self.is_popover && state && key == Key::Escape
+    }
 }
@@ -1,14 +1,19 @@
-use super::{ConcealerConfig, Widget, WidgetConfig};
-use crate::{channels::ConcealerManagerReturn, widgets::concealer::Concealer};
-use quicksilver::{geom::Vector, graphics::Graphics, Result, Window};
-use std::{cell::RefCell, rc::Rc};
+use super::{ConcealerConfig, GlobalClick, Widget, WidgetConfig};
+use crate::{
+    channels::{ConcealerManagerEvent, ConcealerManagerReturn},
+    widgets::concealer::Concealer,
+};
+use quicksilver::{geom::Vector, graphics::Graphics, input::Key, Result, Window};
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Sender};
 
 pub struct ConcealerManagerConfig {
+    ///The concealers to manage. Opening on hover and closing popovers goes through the manager, so only one is shown at a time
     pub concealers: Vec<ConcealerConfig>,
 }
 pub struct ConcealerManager {
     concealers: Vec<Concealer>,
     pub active: Rc<RefCell<Option<usize>>>,
+    event_sender: Sender<ConcealerManagerEvent>,
 }
 impl WidgetConfig<ConcealerManagerReturn, ConcealerManager> for ConcealerManagerConfig {
     fn to_widget(self) -> (ConcealerManager, ConcealerManagerReturn) {
@@ -24,11 +29,12 @@ impl WidgetConfig<ConcealerManagerReturn, ConcealerManager> for ConcealerManager
         let channels = channels;
         let widgets = widgets;
         let shown = Rc::new(RefCell::new(None));
+        let channels = ConcealerManagerReturn::new(channels, shown.clone());
         let manager = ConcealerManager {
-            active: shown.clone(),
+            active: shown,
             concealers: widgets,
+            event_sender: channels.event_sender.clone(),
         };
-        let channels = ConcealerManagerReturn::new(channels, shown);
         (manager, channels)
     }
 }
@@ -39,6 +45,37 @@ impl ConcealerManager {
     fn get_hovered(&self, pos: Vector) -> Option<&Concealer> {
         self.concealers.iter().find(|v| v.contains(pos))
     }
+    ///Gets the index of the concealer whose button is at the given location
+    pub(crate) fn get_button_index(&self, pos: Vector) -> Option<usize> {
+        self.concealers
+            .iter()
+            .position(|widget| widget.button.contains(pos))
+    }
+    pub(crate) fn concealers_mut(&mut self) -> &mut [Concealer] {
+        &mut self.concealers
+    }
+    ///Shows the given concealer and hides the one that was shown before
+    pub(crate) fn set_active(&mut self, new_active: Option<usize>) {
+        let new_active = new_active.filter(|index| *index < self.concealers.len());
+        let old_active = self.active.replace(new_active);
+        if let Some(widget) = old_active.and_then(|index| self.concealers.get_mut(index)) {
+            widget.set_is_concealing(false);
+        }
+        if let Some(widget) = new_active.and_then(|index| self.concealers.get_mut(index)) {
+            widget.set_is_concealing(true);
+        }
+    }
+    ///The same as set_active, but sends a Changed event if the active concealer changed
+    pub(crate) fn select(&mut self, new_active: Option<usize>) {
+        let old_active = *self.active.borrow();
+        self.set_active(new_active);
+        let new_active = *self.active.borrow();
+        if old_active != new_active {
+            let _ = self
+                .event_sender
+                .send(ConcealerManagerEvent::Changed(new_active));
+        }
+    }
 }
 impl Widget for ConcealerManager {
     fn contains(&self, pos: Vector) -> bool {
@@ -48,11 +85,16 @@ impl Widget for ConcealerManager {
         false
     }
     fn render(&mut self, gfx: &mut Graphics, w: &Window) -> Result<()> {
+        let hover_opened = self
+            .concealers
+            .iter_mut()
+            .position(|concealer| concealer.take_hover_open());
+        if hover_opened.is_some() {
+            self.select(hover_opened);
+        }
         self.concealers
             .iter_mut()
-            .enumerate()
-            .map(|(_, widget)| widget.render(gfx, w))
-            .collect::<Result<_>>()
+            .try_for_each(|widget| widget.render(gfx, w))
     }
     fn get_cursor_on_hover(&self, pos: Vector) -> quicksilver::CursorIcon {
         self.get_hovered(pos)
@@ -65,46 +107,41 @@ impl Widget for ConcealerManager {
         }
     }
     fn set_hover(&mut self, pos: Vector, state: bool) {
-        if let Some(x) = self.get_hovered_mut(pos) {
-            x.set_hover(pos, state)
+        for concealer in &mut self.concealers {
+            let hover = state && concealer.contains(pos);
+            concealer.set_hover(pos, hover);
         }
     }
     fn on_click(&mut self, pos: Vector) {
-        let on_button = self
-            .concealers
-            .iter_mut()
-            .enumerate()
-            .find(|(_, widget)| widget.button.contains(pos));
-        let mut current_active = self.active.borrow_mut();
-        match (on_button, current_active.as_ref()) {
-            (Some(button), Some(cur)) if button.0 != *cur => {
-                let (key, widget) = button;
-                widget.on_click(pos);
-                widget.set_is_concealing(true);
-                if let Some(cur_widget) = self.concealers.get_mut(*cur) {
-                    cur_widget.set_is_concealing(false)
-                }
-                current_active.replace(key);
-            }
-            (Some(button), Some(cur)) if button.0 == *cur => {
-                let (_, widget) = button;
-                widget.on_click(pos);
-                widget.set_is_concealing(false);
-                *current_active = None;
-            }
-            (Some(button), None) => {
-                let (key, widget) = button;
-                widget.on_click(pos);
-                widget.set_is_concealing(true);
-                *current_active = Some(key);
-            }
-            (None, Some(cur)) => {
-                if let Some(widget) = self.concealers.get_mut(*cur) {
-                    widget.on_click(pos);
-                }
-            }
-            (None, None) => {}
-            (Some(_), Some(_)) => unreachable!("How....."),
+        if let Some(index) = self.get_button_index(pos) {
+            self.concealers[index].click_button(pos);
+            //clicking on the button of the active concealer closes it
+            let new_active = if *self.active.borrow() == Some(index) {
+                None
+            } else {
+                Some(index)
+            };
+            self.select(new_active);
+        }
+    }
+    fn on_global_click(&mut self, click: &GlobalClick) {
+        let active = *self.active.borrow();
+        let closes = active
+            .and_then(|index| self.concealers.get(index))
+            .map(|concealer| concealer.closes_on_click(click))
+            .unwrap_or(false);
+        if closes {
+            self.select(None);
+        }
+    }
+    fn on_global_key_press(&mut self, key: Key, state: bool) {
+        let active = *self.active.borrow();
+        let closes = active
+            .and_then(|index| self.concealers.get(index))
+            .map(|concealer| concealer.closes_on_key_press(key, state))
+            .unwrap_or(false);
+        if closes {
+            self.select(None);
         }
     }
 }
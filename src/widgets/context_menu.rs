@@ -0,0 +1,449 @@
+use super::{dropdown::DropDownEntryKind, GlobalClick, Widget, WidgetConfig};
+use crate::{
+    channels::{ContextMenuChannel, ContextMenuEvent},
+    MFont,
+};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics, Image},
+    input::Key,
+    Result, Window,
+};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc::{self, Sender},
+};
+
+///A single item of a context menu
+pub struct ContextMenuItem<T> {
+    ///Send back through the channel when this item is chosen
+    pub id: T,
+    pub label: String,
+    pub icon: Option<Image>,
+    ///Text drawn on the right side of the item, like "Ctrl+C". The menu doesn't react to the shortcut itself
+    pub shortcut: Option<String>,
+    ///Disabled items are drawn faded and can't be chosen
+    pub disabled: bool,
+    ///If set, choosing this item opens another menu next to it instead
+    pub submenu: Option<Vec<ContextMenuItem<T>>>,
+    ///Separators and group headers can't be chosen. See DropDownEntryKind
+    pub kind: DropDownEntryKind,
+    ///If set, the item is checkable and a box is drawn in front of it, instead of the icon.
+    ///Choosing it flips the state and sends a Toggled event instead of Selected
+    pub checked: Option<bool>,
+}
+
+impl<T> ContextMenuItem<T> {
+    pub fn new(id: T, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            icon: None,
+            shortcut: None,
+            disabled: false,
+            submenu: None,
+            kind: DropDownEntryKind::Option,
+            checked: None,
+        }
+    }
+    ///A line used to divide the items into groups
+    pub fn separator(id: T) -> Self {
+        Self::new(id, "").with_kind(DropDownEntryKind::Separator)
+    }
+    pub fn with_icon(mut self, icon: Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+    pub fn with_submenu(mut self, submenu: Vec<ContextMenuItem<T>>) -> Self {
+        self.submenu = Some(submenu);
+        self
+    }
+    pub fn with_kind(mut self, kind: DropDownEntryKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+    fn is_selectable(&self) -> bool {
+        self.kind == DropDownEntryKind::Option && !self.disabled
+    }
+}
+
+///Configures a context menu. Open it with Context::open_context_menu
+pub struct ContextMenuConfig<T> {
+    pub items: Vec<ContextMenuItem<T>>,
+    ///Where the top left corner of the menu is drawn. If not set, the menu opens at the cursor.
+    ///The menu gets moved if it doesn't fit in the window
+    pub location: Option<Vector>,
+    ///The size of every item
+    pub item_size: Vector,
+    pub font: MFont,
+    pub text_color: Color,
+    pub background_color: Color,
+    ///The color drawn behind the highlighted items
+    pub highlight_color: Color,
+    pub border_color: Color,
+}
+
+///A menu, or one of its open submenus
+struct MenuLevel {
+    location: Vector,
+    highlighted: Option<usize>,
+}
+
+pub struct ContextMenu<T> {
+    pub items: Vec<ContextMenuItem<T>>,
+    pub item_size: Vector,
+    pub font: MFont,
+    pub text_color: Color,
+    pub background_color: Color,
+    pub highlight_color: Color,
+    pub border_color: Color,
+    ///The main menu followed by every open submenu
+    levels: Vec<MenuLevel>,
+    window_size: Option<Vector>,
+    pub(crate) is_open: Rc<RefCell<bool>>,
+    pub(crate) event_sender: Sender<ContextMenuEvent<T>>,
+}
+
+impl<T: Clone> WidgetConfig<ContextMenuChannel<T>, ContextMenu<T>> for ContextMenuConfig<T> {
+    fn to_widget(self) -> (ContextMenu<T>, ContextMenuChannel<T>) {
+        let is_open = Rc::new(RefCell::new(true));
+        let (event_sender, events) = mpsc::channel();
+        (
+            ContextMenu {
+                items: self.items,
+                item_size: self.item_size,
+                font: self.font,
+                text_color: self.text_color,
+                background_color: self.background_color,
+                highlight_color: self.highlight_color,
+                border_color: self.border_color,
+                levels: vec![MenuLevel {
+                    location: self.location.unwrap_or(Vector::ZERO),
+                    highlighted: None,
+                }],
+                window_size: None,
+                is_open: is_open.clone(),
+                event_sender,
+            },
+            ContextMenuChannel { is_open, events },
+        )
+    }
+}
+
+impl<T: Clone> ContextMenu<T> {
+    pub(crate) fn is_open(&self) -> bool {
+        *self.is_open.borrow()
+    }
+    ///Opens the menu at the given location with every submenu closed
+    pub(crate) fn open_at(&mut self, location: Vector) {
+        self.levels = vec![MenuLevel {
+            location,
+            highlighted: None,
+        }];
+        let size = self.get_level_rec(0).size;
+        self.levels[0].location = self.fit_in_window(location, size, location.x);
+        self.is_open.replace(true);
+    }
+    ///The amount of menus that are open, including the main one
+    pub(crate) fn depth(&self) -> usize {
+        self.levels.len()
+    }
+    ///If the highlighted item of the deepest open menu has a submenu
+    pub(crate) fn has_submenu_highlighted(&self) -> bool {
+        let level = self.levels.len() - 1;
+        self.levels[level]
+            .highlighted
+            .and_then(|index| self.items_at(level).get(index))
+            .map(|item| item.submenu.is_some())
+            .unwrap_or(false)
+    }
+    ///The items shown by the given menu level
+    fn items_at(&self, level: usize) -> &[ContextMenuItem<T>] {
+        let mut items = &self.items[..];
+        for menu in &self.levels[..level] {
+            items = menu
+                .highlighted
+                .and_then(|index| items.get(index))
+                .and_then(|item| item.submenu.as_deref())
+                .unwrap_or(&[]);
+        }
+        items
+    }
+    fn items_at_mut(&mut self, level: usize) -> &mut [ContextMenuItem<T>] {
+        let mut items = &mut self.items[..];
+        for menu in &self.levels[..level] {
+            items = match menu.highlighted {
+                Some(index) => match items[index].submenu.as_deref_mut() {
+                    Some(submenu) => submenu,
+                    None => return &mut [],
+                },
+                None => return &mut [],
+            };
+        }
+        items
+    }
+    ///The area of the given menu level
+    pub fn get_level_rec(&self, level: usize) -> Rectangle {
+        let size = Vector::new(
+            self.item_size.x,
+            self.item_size.y * self.items_at(level).len() as f32,
+        );
+        Rectangle::new(self.levels[level].location, size)
+    }
+    fn get_item_rec(&self, level: usize, index: usize) -> Rectangle {
+        let mut location = self.levels[level].location;
+        location.y += self.item_size.y * index as f32;
+        Rectangle::new(location, self.item_size)
+    }
+    ///Finds the menu level and item at the given location. Submenus are checked first, as they are drawn on top
+    fn vector_to_item(&self, point: Vector) -> Option<(usize, usize)> {
+        (0..self.levels.len()).rev().find_map(|level| {
+            (0..self.items_at(level).len())
+                .find(|index| self.get_item_rec(level, *index).contains(point))
+                .map(|index| (level, index))
+        })
+    }
+    ///Moves a menu so it fits inside the window.
+    ///If it doesn't fit on the right, it is placed left of the given x instead
+    fn fit_in_window(&self, mut location: Vector, size: Vector, left_of: f32) -> Vector {
+        if let Some(window) = self.window_size {
+            if location.x + size.x > window.x {
+                location.x = left_of - size.x;
+            }
+            if location.y + size.y > window.y {
+                location.y = window.y - size.y;
+            }
+            location.x = location.x.max(0.0);
+            location.y = location.y.max(0.0);
+        }
+        location
+    }
+    ///Highlights the given item and closes every submenu below it
+    fn highlight(&mut self, level: usize, index: Option<usize>) {
+        self.levels.truncate(level + 1);
+        self.levels[level].highlighted = index;
+    }
+    ///Opens the submenu of the highlighted item of the given level, if it has one
+    fn open_submenu(&mut self, level: usize) -> bool {
+        let index = match self.levels[level].highlighted {
+            Some(index) => index,
+            None => return false,
+        };
+        let has_submenu = self
+            .items_at(level)
+            .get(index)
+            .map(|item| item.is_selectable() && item.submenu.is_some())
+            .unwrap_or(false);
+        if !has_submenu {
+            return false;
+        }
+        self.levels.truncate(level + 1);
+        let parent = self.get_item_rec(level, index);
+        self.levels.push(MenuLevel {
+            location: parent.pos + Vector::new(parent.width(), 0.),
+            highlighted: None,
+        });
+        let size = self.get_level_rec(level + 1).size;
+        let location = self.fit_in_window(self.levels[level + 1].location, size, parent.pos.x);
+        self.levels[level + 1].location = location;
+        true
+    }
+    ///Opens the submenu of the highlighted item and highlights its first item, so it can be used with the keyboard
+    fn enter_submenu(&mut self, level: usize) {
+        if self.open_submenu(level) {
+            self.levels[level + 1].highlighted = self.next_selectable(level + 1, None, true);
+        }
+    }
+    ///Chooses the given item. Items with a submenu open it instead
+    fn activate(&mut self, level: usize, index: usize) {
+        let item = match self.items_at(level).get(index) {
+            Some(item) if item.is_selectable() => item,
+            _ => return,
+        };
+        if item.submenu.is_some() {
+            self.highlight(level, Some(index));
+            self.enter_submenu(level);
+            return;
+        }
+        let event = match item.checked {
+            Some(checked) => {
+                self.items_at_mut(level)[index].checked = Some(!checked);
+                ContextMenuEvent::Toggled(self.items_at(level)[index].id.clone(), !checked)
+            }
+            None => ContextMenuEvent::Selected(item.id.clone()),
+        };
+        let _ = self.event_sender.send(event);
+        self.is_open.replace(false);
+    }
+    pub(crate) fn close(&mut self) {
+        if self.is_open.replace(false) {
+            let _ = self.event_sender.send(ContextMenuEvent::Closed);
+        }
+    }
+    ///Finds the next item that can be selected, wrapping around at the ends
+    fn next_selectable(&self, level: usize, from: Option<usize>, forward: bool) -> Option<usize> {
+        let items = self.items_at(level);
+        let len = items.len();
+        (1..=len)
+            .map(|step| match (from, forward) {
+                (Some(from), true) => (from + step) % len,
+                (Some(from), false) => (from + len - step) % len,
+                (None, true) => step - 1,
+                (None, false) => len - step,
+            })
+            .find(|index| items[*index].is_selectable())
+    }
+    fn draw_level(&self, gfx: &mut Graphics, level: usize) -> Result<()> {
+        let area = self.get_level_rec(level);
+        gfx.fill_rect(&area, self.background_color);
+        for (index, item) in self.items_at(level).iter().enumerate() {
+            let location = self.get_item_rec(level, index);
+            if item.kind == DropDownEntryKind::Separator {
+                let line = Rectangle::new(
+                    Vector::new(location.pos.x, location.center().y),
+                    Vector::new(location.width(), 1.0),
+                );
+                gfx.fill_rect(&line, self.border_color);
+                continue;
+            }
+            if self.levels[level].highlighted == Some(index) && item.is_selectable() {
+                gfx.fill_rect(&location, self.highlight_color);
+            }
+            let color = if item.disabled {
+                self.text_color.with_alpha(self.text_color.a * 0.5)
+            } else {
+                self.text_color
+            };
+            let icon_size = location.height();
+            let icon_area = Rectangle::new(location.pos, Vector::new(icon_size, icon_size));
+            match (item.checked, &item.icon) {
+                (Some(checked), _) => {
+                    let size = icon_size / 2.0;
+                    let check_box = Rectangle::new(
+                        icon_area.center() - Vector::new(size, size) / 2.0,
+                        Vector::new(size, size),
+                    );
+                    gfx.stroke_rect(&check_box, color);
+                    if checked {
+                        let mark = Rectangle::new(
+                            check_box.pos + check_box.size / 4.0,
+                            check_box.size / 2.0,
+                        );
+                        gfx.fill_rect(&mark, color);
+                    }
+                }
+                (None, Some(icon)) => gfx.draw_image(icon, icon_area),
+                (None, None) => {}
+            }
+            let text_y = location.pos.y + self.font.size;
+            self.font.draw(
+                gfx,
+                &item.label,
+                color,
+                Vector::new(location.pos.x + icon_size, text_y),
+            )?;
+            let hint = match (&item.submenu, &item.shortcut) {
+                (Some(_), _) => Some(">"),
+                (None, Some(shortcut)) => Some(shortcut.as_str()),
+                (None, None) => None,
+            };
+            if let Some(hint) = hint {
+                //measure the text first, so it can be aligned to the right
+                let size = self.font.layout_glyphs(gfx, hint, None, |_, _| {})?;
+                let x = location.pos.x + location.width() - size.x - icon_size / 2.0;
+                self.font.draw(gfx, hint, color, Vector::new(x, text_y))?;
+            }
+        }
+        gfx.stroke_rect(&area, self.border_color);
+        Ok(())
+    }
+}
+
+impl<T: Clone> Widget for ContextMenu<T> {
+    fn contains(&self, point: Vector) -> bool {
+        self.is_open()
+            && (0..self.levels.len()).any(|level| self.get_level_rec(level).contains(point))
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
+        if !self.is_open() {
+            return Ok(());
+        }
+        let window_size = window.size();
+        if self.window_size != Some(window_size) {
+            self.window_size = Some(window_size);
+            let root = self.get_level_rec(0);
+            self.levels[0].location = self.fit_in_window(root.pos, root.size, root.pos.x);
+        }
+        for level in 0..self.levels.len() {
+            self.draw_level(gfx, level)?;
+        }
+        Ok(())
+    }
+    fn set_hover(&mut self, point: Vector, state: bool) {
+        if !state || !self.is_open() {
+            return;
+        }
+        if let Some((level, index)) = self.vector_to_item(point) {
+            if self.levels[level].highlighted != Some(index) {
+                self.highlight(level, Some(index));
+                self.open_submenu(level);
+            }
+        }
+    }
+    fn on_click(&mut self, point: Vector) {
+        if let Some((level, index)) = self.vector_to_item(point) {
+            self.activate(level, index);
+        }
+    }
+    fn on_global_click(&mut self, click: &GlobalClick) {
+        if self.is_open() && !self.contains(click.location) {
+            self.close();
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if !state || !self.is_open() {
+            return;
+        }
+        let level = self.levels.len() - 1;
+        let highlighted = self.levels[level].highlighted;
+        match key {
+            Key::Down | Key::Up => {
+                let next = self.next_selectable(level, highlighted, key == Key::Down);
+                self.levels[level].highlighted = next;
+            }
+            Key::Right => self.enter_submenu(level),
+            Key::Left if level > 0 => {
+                self.levels.pop();
+            }
+            Key::Return | Key::NumpadEnter | Key::Space => {
+                if let Some(index) = highlighted {
+                    self.activate(level, index);
+                }
+            }
+            Key::Escape if level > 0 => {
+                self.levels.pop();
+            }
+            Key::Escape => self.close(),
+            _ => {}
+        }
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
@@ -1,14 +1,38 @@
 use super::{Widget, WidgetConfig};
-use crate::{channels::Dropdown as Channel, FontStyle};
+use crate::{
+    channels::{Dropdown as Channel, DropdownEvent},
+    FontStyle,
+};
 use quicksilver::{
     geom::Vector,
     geom::{Rectangle, Shape},
     graphics::Color,
     graphics::{Graphics, Image},
-    Result, Window,
+    input::{Key, ScrollDelta},
+    Result, Timer, Window,
+};
+
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    rc::Rc,
+    sync::mpsc::{self, Sender},
+    time::Duration,
 };
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+pub(crate) const SCROLLBAR_WIDTH: f32 = 8.0;
+
+///What kind of entry a value inside a dropdown is
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum DropDownEntryKind {
+    ///A normal option that can be selected
+    #[default]
+    Option,
+    ///A header above a group of options. Can't be selected
+    GroupHeader,
+    ///A line between options. Can't be selected and its text isn't shown
+    Separator,
+}
 
 ///used to configure every value that a dropdown contains.
 #[derive(Clone)]
@@ -18,8 +42,72 @@ pub struct DropDownValueConfig<T: Clone> {
     ///what is displayed in the list
     pub text: String,
     pub normal_font_style: FontStyle,
-    ///what is displayed if the user hovers over it
+    ///what is displayed if the user hovers over it or highlights it with the keyboard.
+    ///If not set, a faded divider color is drawn behind it instead
     pub hover_font_style: Option<FontStyle>,
+    ///If this is an option, a group header or a separator
+    pub kind: DropDownEntryKind,
+    ///Disabled options are greyed out and can't be selected
+    pub disabled: bool,
+    ///An image that is drawn left of the text
+    pub icon: Option<Image>,
+}
+impl<T: Clone> DropDownValueConfig<T> {
+    ///Returns if the user can select this value
+    pub fn is_selectable(&self) -> bool {
+        self.kind == DropDownEntryKind::Option && !self.disabled
+    }
+    pub fn with_kind(mut self, kind: DropDownEntryKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+    pub fn with_icon(mut self, icon: Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+    ///Draws this value inside the given location.
+    ///Separators are drawn as a line using the given divider color and size
+    pub(crate) fn draw(
+        &self,
+        gfx: &mut Graphics,
+        font_style: &FontStyle,
+        location: Rectangle,
+        divider_color: Color,
+        divider_size: f32,
+    ) -> Result<()> {
+        if self.kind == DropDownEntryKind::Separator {
+            let line = Rectangle::new(
+                Vector::new(
+                    location.pos.x,
+                    location.pos.y + (location.height() - divider_size) / 2.0,
+                ),
+                Vector::new(location.width(), divider_size),
+            );
+            gfx.fill_rect(&line, divider_color);
+            return Ok(());
+        }
+        let mut text_pos = location.pos;
+        if let Some(icon) = &self.icon {
+            let icon_size = location.height();
+            gfx.draw_image(
+                icon,
+                Rectangle::new(location.pos, Vector::new(icon_size, icon_size)),
+            );
+            text_pos.x += icon_size;
+        }
+        text_pos.y += font_style.font.size;
+        let color = if self.disabled {
+            font_style.color.with_alpha(font_style.color.a * 0.5)
+        } else {
+            font_style.color
+        };
+        font_style.font.draw(gfx, &self.text, color, text_pos)?;
+        Ok(())
+    }
 }
 impl<T: Clone> From<(T, String, FontStyle)> for DropDownValueConfig<T> {
     fn from(val: (T, String, FontStyle)) -> DropDownValueConfig<T> {
@@ -28,6 +116,9 @@ impl<T: Clone> From<(T, String, FontStyle)> for DropDownValueConfig<T> {
             text: val.1,
             normal_font_style: val.2,
             hover_font_style: None,
+            kind: DropDownEntryKind::Option,
+            disabled: false,
+            icon: None,
         }
     }
 }
@@ -38,6 +129,9 @@ impl<T: Clone> From<(T, String, FontStyle, FontStyle)> for DropDownValueConfig<T
             text: val.1,
             normal_font_style: val.2,
             hover_font_style: Some(val.3),
+            kind: DropDownEntryKind::Option,
+            disabled: false,
+            icon: None,
         }
     }
 }
@@ -49,6 +143,9 @@ impl<T: Clone + ToString> From<(T, FontStyle, FontStyle)> for DropDownValueConfi
             text: as_text,
             normal_font_style: val.1,
             hover_font_style: Some(val.2),
+            kind: DropDownEntryKind::Option,
+            disabled: false,
+            icon: None,
         }
     }
 }
@@ -60,9 +157,24 @@ impl<T: Clone + ToString> From<(T, FontStyle)> for DropDownValueConfig<T> {
             text: as_text,
             normal_font_style: val.1,
             hover_font_style: None,
+            kind: DropDownEntryKind::Option,
+            disabled: false,
+            icon: None,
         }
     }
 }
+///The direction in which the list of a dropdown opens
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum OpenDirection {
+    ///Opens below the dropdown, unless there is only room above it inside the window
+    #[default]
+    Auto,
+    ///Always opens below the dropdown
+    Down,
+    ///Always opens above the dropdown
+    Up,
+}
+
 ///Configures the dropdown widget
 pub struct DropDownConfig<T: Clone, ValueConfig: Into<DropDownValueConfig<T>>> {
     ///A list of selectable values and how to render them. See DropDownValueConfig
@@ -81,6 +193,10 @@ pub struct DropDownConfig<T: Clone, ValueConfig: Into<DropDownValueConfig<T>>> {
     pub divider_color: Color,
     ///The width of the line between and arround every option
     pub divider_size: f32,
+    ///How many options are shown at the same time. If there are more, the list can be scrolled
+    pub max_visible: Option<usize>,
+    ///In which direction the list opens. See OpenDirection
+    pub open_direction: OpenDirection,
     pub t: PhantomData<T>,
 }
 
@@ -95,6 +211,23 @@ pub struct DropDown<T: Clone> {
     pub hover_over: Option<Vector>,
     pub divider_color: Color,
     pub divider_size: f32,
+    ///The option that is highlighted using the keyboard
+    pub highlighted: Option<usize>,
+    pub max_visible: Option<usize>,
+    pub open_direction: OpenDirection,
+    ///The size of the window the last time the dropdown got rendered
+    pub window_size: Option<Vector>,
+    ///The offset of the layer the dropdown is on, used to find where it is inside the window
+    pub layer_offset: Vector,
+    ///The index of the first option that is visible
+    pub scroll_offset: usize,
+    scroll_remainder: f32,
+    drag_start: Option<(f32, usize)>,
+    ///If the list was open the last time the dropdown got rendered, used to notice when the channel opened it
+    was_open: bool,
+    event_sender: Sender<DropdownEvent<T>>,
+    type_ahead: String,
+    type_ahead_timer: Timer,
 }
 
 impl<T: Clone, X: Into<DropDownValueConfig<T>>> WidgetConfig<Channel<T>, DropDown<T>>
@@ -105,10 +238,12 @@ impl<T: Clone, X: Into<DropDownValueConfig<T>>> WidgetConfig<Channel<T>, DropDow
         let is_open = Rc::new(RefCell::new(false));
         let selected = Rc::new(RefCell::new(self.selected));
         let values = Rc::new(RefCell::new(values));
+        let (event_sender, events) = mpsc::channel();
         let channel = Channel {
             values: values.clone(),
             is_open: is_open.clone(),
             selected: selected.clone(),
+            events,
         };
 
         (
@@ -123,6 +258,18 @@ impl<T: Clone, X: Into<DropDownValueConfig<T>>> WidgetConfig<Channel<T>, DropDow
                 is_open,
                 selected,
                 hover_over: None,
+                highlighted: None,
+                max_visible: self.max_visible,
+                open_direction: self.open_direction,
+                window_size: None,
+                layer_offset: Vector::ZERO,
+                scroll_offset: 0,
+                scroll_remainder: 0.0,
+                drag_start: None,
+                was_open: false,
+                event_sender,
+                type_ahead: String::new(),
+                type_ahead_timer: Timer::with_duration(Duration::from_secs(1)),
             },
             channel,
         )
@@ -145,83 +292,200 @@ impl<T: Clone> Widget for DropDown<T> {
             self.hover_over = None;
         }
     }
-    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+    fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
+        self.window_size = Some(window.size());
+        let is_open = self.is_open();
+        if is_open && !self.was_open {
+            if let Some(selected) = self.selected() {
+                self.scroll_to(selected);
+            }
+        }
+        self.was_open = is_open;
         gfx.draw_image(&self.open_button, self.get_location_open_button());
         self.draw_arround_rec(&self.location, gfx);
         let values = self.values.borrow();
         let selected = self
             .selected()
             .and_then(|v| values.get(v))
-            .or_else(|| values.get(0));
+            .or_else(|| values.iter().find(|v| v.is_selectable()));
 
         if let Some(selected) = selected {
-            let mut pos = self.location.pos;
-            pos.y += selected.normal_font_style.font.size;
-            selected.normal_font_style.font.draw(
+            selected.draw(
                 gfx,
-                &selected.text,
-                selected.normal_font_style.color,
-                pos,
+                &selected.normal_font_style,
+                self.location,
+                self.divider_color,
+                self.divider_size,
             )?;
         }
         drop(values);
-        let hovered = self.hover_over.and_then(|v| self.vector_to_index(v));
+        let hovered = self
+            .hover_over
+            .and_then(|v| self.vector_to_index(v))
+            .or(self.highlighted);
 
         if self.is_open() {
-            self.values
-                .borrow_mut()
-                .iter_mut()
+            self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+            let visible = self.visible_count();
+            let values = self.values.borrow();
+            for (row, (index, value)) in values
+                .iter()
                 .enumerate()
-                .map(|(key, value)| match hovered {
-                    Some(x) => {
-                        if x == key {
-                            (
-                                &value.text,
-                                value
-                                    .hover_font_style
-                                    .as_ref()
-                                    .unwrap_or(&value.normal_font_style),
-                                key,
-                            )
-                        } else {
-                            (&value.text, &value.normal_font_style, key)
-                        }
+                .skip(self.scroll_offset)
+                .take(visible)
+                .enumerate()
+            {
+                let is_hovered = hovered == Some(index) && value.is_selectable();
+                let mut location = self.location;
+                location.pos.y = self.list_top() + self.option_height * row as f32;
+                location.size.y = self.option_height;
+                let font_style = match &value.hover_font_style {
+                    Some(hover) if is_hovered => hover,
+                    //without a hover style, the highlighted option still needs to stand out
+                    None if is_hovered => {
+                        let color = self.divider_color;
+                        gfx.fill_rect(&location, color.with_alpha(color.a * 0.3));
+                        &value.normal_font_style
                     }
-                    None => (&value.text, &value.normal_font_style, key),
-                })
-                .map(|(text, font, key)| (text, font, (key + 1) as f32))
-                .map(|(text, font, index)| {
-                    let mut loc_box = self.location;
-                    let mut loc_text = self.location;
-                    loc_box.pos.y += self.option_height * index;
-                    loc_text.pos.y += (self.option_height * index) + font.font.size;
-                    (text, font, loc_box, loc_text)
-                })
-                .map(|(text, font_style, location_box, location_text)| {
-                    font_style
-                        .font
-                        .draw(gfx, text, font_style.color, location_text.pos)?;
-                    self.draw_arround_rec(&location_box, gfx);
-                    Ok(())
-                })
-                .collect::<Result<_>>()?;
+                    _ => &value.normal_font_style,
+                };
+                value.draw(
+                    gfx,
+                    font_style,
+                    location,
+                    self.divider_color,
+                    self.divider_size,
+                )?;
+                if value.kind != DropDownEntryKind::Separator {
+                    self.draw_arround_rec(&location, gfx);
+                }
+            }
+            drop(values);
+            if let Some(track) = self.get_scrollbar_rec() {
+                gfx.fill_rect(&self.get_scrollbar_thumb_rec(track), self.divider_color);
+                self.draw_arround_rec(&track, gfx);
+            }
         }
         Ok(())
     }
     fn on_click(&mut self, pos: Vector) {
+        if self.is_open() && self.is_on_scrollbar(pos) {
+            self.drag_start = Some((pos.y, self.scroll_offset));
+            return;
+        }
         if let Some(selected) = self.vector_to_index(pos) {
-            self.selected.swap(&RefCell::new(Some(selected)));
+            if !self.is_selectable(selected) {
+                //clicking on a header, separator or disabled option keeps the list open
+                return;
+            }
+            self.select(selected);
         }
 
-        let open = *self.is_open.borrow();
-        self.is_open.swap(&RefCell::new(!open));
+        let open = self.is_open();
+        self.set_open(!open);
+    }
+    fn on_drag(&mut self, pos: Vector) {
+        if let (Some((start_y, start_offset)), Some(track)) =
+            (self.drag_start, self.get_scrollbar_rec())
+        {
+            let len = self.values.borrow().len() as f32;
+            let moved = (pos.y - start_y) / track.height() * len;
+            let offset = (start_offset as f32 + moved).round().max(0.0) as usize;
+            self.scroll_offset = offset.min(self.max_scroll_offset());
+        }
+    }
+    fn on_release(&mut self, _: Vector) {
+        self.drag_start = None;
+    }
+    fn on_scroll(&mut self, delta: &ScrollDelta) {
+        if !self.is_open() {
+            return;
+        }
+        let options = match delta {
+            ScrollDelta::Lines(amount) => -amount.y,
+            ScrollDelta::Pixels(amount) => -amount.y / self.option_height,
+        };
+        self.scroll_remainder += options;
+        let whole_options = self.scroll_remainder.trunc();
+        self.scroll_remainder -= whole_options;
+        let offset = (self.scroll_offset as f32 + whole_options).max(0.0) as usize;
+        self.scroll_offset = offset.min(self.max_scroll_offset());
     }
     fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
         quicksilver::CursorIcon::Hand
     }
     fn set_focus(&mut self, _: Vector, focus: bool) {
         if !focus {
-            self.is_open.swap(&RefCell::new(focus));
+            self.set_open(false);
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if !state {
+            return;
+        }
+        match (key, self.is_open()) {
+            (Key::Down, true) => {
+                if let Some(next) = self.next_selectable(self.highlighted, true, true) {
+                    self.highlighted = Some(next);
+                }
+            }
+            (Key::Up, true) => {
+                if let Some(next) = self.next_selectable(self.highlighted, false, true) {
+                    self.highlighted = Some(next);
+                }
+            }
+            (Key::Down, false) => {
+                if let Some(next) = self.next_selectable(self.selected(), true, false) {
+                    self.select(next);
+                }
+            }
+            (Key::Up, false) => {
+                if let Some(next) = self.next_selectable(self.selected(), false, false) {
+                    self.select(next);
+                }
+            }
+            //while typing ahead, space is part of the text that is searched for
+            (Key::Space, _) if self.is_typing_ahead() => {}
+            (Key::Return, false) | (Key::NumpadEnter, false) | (Key::Space, false) => {
+                self.highlighted = self.selected();
+                self.set_open(true);
+            }
+            (Key::Return, true) | (Key::NumpadEnter, true) | (Key::Space, true) => {
+                if let Some(highlighted) = self.highlighted {
+                    self.select(highlighted);
+                }
+                self.set_open(false);
+            }
+            (Key::Escape, true) => self.set_open(false),
+            _ => {}
+        }
+        if let Some(highlighted) = self.highlighted {
+            self.scroll_to(highlighted);
+        }
+    }
+    fn set_layer_offset(&mut self, offset: Vector) {
+        self.layer_offset = offset;
+    }
+    fn on_typed(&mut self, typed: char) {
+        if typed.is_control() || (typed == ' ' && !self.is_typing_ahead()) {
+            return;
+        }
+        if !self.is_typing_ahead() {
+            self.type_ahead.clear();
+        }
+        self.type_ahead_timer.reset();
+        self.type_ahead.extend(typed.to_lowercase());
+        let found =
+            self.values.borrow().iter().position(|v| {
+                v.is_selectable() && v.text.to_lowercase().starts_with(&self.type_ahead)
+            });
+        if let Some(found) = found {
+            if self.is_open() {
+                self.highlighted = Some(found);
+                self.scroll_to(found);
+            } else {
+                self.select(found);
+            }
         }
     }
 }
@@ -232,35 +496,168 @@ impl<T: Clone> DropDown<T> {
         open_button_location.pos.x = self.location.pos.x + self.location.width();
         open_button_location
     }
+    ///The area covered by the dropdown and its list
     pub fn get_open_rec(&self) -> Rectangle {
         let mut rec = self.location;
-        rec.size.y =
-            self.location.size.y + (self.option_height * self.values.borrow().len() as f32);
+        rec.size.y = self.location.size.y + self.list_height();
+        if self.opens_upward() {
+            rec.pos.y -= self.list_height();
+        }
         rec
     }
+    fn list_height(&self) -> f32 {
+        self.option_height * self.visible_count() as f32
+    }
+    ///Returns true if the list is drawn above the dropdown
+    pub fn opens_upward(&self) -> bool {
+        match self.open_direction {
+            OpenDirection::Down => false,
+            OpenDirection::Up => true,
+            OpenDirection::Auto => {
+                let window_size = match self.window_size {
+                    Some(size) => size,
+                    None => return false,
+                };
+                let top = self.location.pos.y + self.layer_offset.y;
+                let space_below = window_size.y - (top + self.location.height());
+                let space_above = top;
+                space_below < self.list_height() && space_above > space_below
+            }
+        }
+    }
+    ///The y coordinate where the first visible option is drawn
+    fn list_top(&self) -> f32 {
+        if self.opens_upward() {
+            self.location.pos.y - self.list_height()
+        } else {
+            self.location.pos.y + self.location.height()
+        }
+    }
+    ///The amount of options that are shown when the list is open
+    pub fn visible_count(&self) -> usize {
+        let len = self.values.borrow().len();
+        self.max_visible.map(|max| max.min(len)).unwrap_or(len)
+    }
+    fn max_scroll_offset(&self) -> usize {
+        self.values.borrow().len() - self.visible_count()
+    }
+    ///Scrolls the list so the given option is visible
+    pub fn scroll_to(&mut self, index: usize) {
+        let visible = self.visible_count();
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if index >= self.scroll_offset + visible {
+            self.scroll_offset = index + 1 - visible;
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+    }
+    ///The location of the scrollbar. Returns None if every option fits
+    pub fn get_scrollbar_rec(&self) -> Option<Rectangle> {
+        if self.max_scroll_offset() == 0 {
+            return None;
+        }
+        Some(Rectangle::new(
+            Vector::new(
+                self.location.pos.x + self.location.width() - SCROLLBAR_WIDTH,
+                self.list_top(),
+            ),
+            Vector::new(
+                SCROLLBAR_WIDTH,
+                self.option_height * self.visible_count() as f32,
+            ),
+        ))
+    }
+    fn get_scrollbar_thumb_rec(&self, track: Rectangle) -> Rectangle {
+        let len = self.values.borrow().len() as f32;
+        let mut thumb = track;
+        thumb.size.y = track.height() * self.visible_count() as f32 / len;
+        thumb.pos.y += track.height() * self.scroll_offset as f32 / len;
+        thumb
+    }
+    fn is_on_scrollbar(&self, point: Vector) -> bool {
+        self.get_scrollbar_rec()
+            .map(|track| track.contains(point))
+            .unwrap_or(false)
+    }
     pub fn is_open(&self) -> bool {
         *self.is_open.borrow()
     }
     pub fn selected(&self) -> Option<usize> {
         *self.selected.borrow()
     }
+    fn is_selectable(&self, index: usize) -> bool {
+        self.values
+            .borrow()
+            .get(index)
+            .map(|value| value.is_selectable())
+            .unwrap_or(false)
+    }
+    ///Finds the next option that can be selected, skipping headers, separators and disabled options
+    fn next_selectable(&self, from: Option<usize>, forward: bool, wrap: bool) -> Option<usize> {
+        let len = self.values.borrow().len() as isize;
+        let start = match from {
+            Some(from) => from as isize,
+            None if forward => -1,
+            None => len,
+        };
+        (1..=len)
+            .map(|step| if forward { start + step } else { start - step })
+            .take_while(|index| wrap || (0..len).contains(index))
+            .map(|index| index.rem_euclid(len) as usize)
+            .find(|index| self.is_selectable(*index))
+    }
+    fn select(&mut self, index: usize) {
+        let value = match self.values.borrow().get(index) {
+            Some(value) if value.is_selectable() => value.value.clone(),
+            _ => return,
+        };
+        if self.selected.replace(Some(index)) != Some(index) {
+            self.send_event(DropdownEvent::Changed(value));
+        }
+    }
+    fn set_open(&mut self, is_open: bool) {
+        if self.is_open.replace(is_open) != is_open {
+            self.send_event(if is_open {
+                DropdownEvent::Opened
+            } else {
+                DropdownEvent::Closed
+            });
+        }
+        if is_open {
+            if let Some(selected) = self.selected() {
+                self.scroll_to(selected);
+            }
+        } else {
+            self.highlighted = None;
+            self.drag_start = None;
+        }
+    }
 
     pub fn vector_to_index(&self, point: Vector) -> Option<usize> {
-        if !self.is_open() {
+        if !self.is_open() || self.is_on_scrollbar(point) {
             return None;
         }
         let mut offset = point;
-        offset.y -= self.location.pos.y + self.location.height();
+        offset.y -= self.list_top();
         let offset = offset;
-        if offset.y > 0f32 {
-            let len = self.values.borrow().len();
-            let size = len as f32 * self.option_height;
-            let selected = (len as f32 - ((size - offset.y) / self.option_height)).floor() as usize;
-            Some(selected)
+        if offset.y >= 0f32 {
+            let row = (offset.y / self.option_height).floor() as usize;
+            if row < self.visible_count() {
+                Some(row + self.scroll_offset)
+            } else {
+                None
+            }
         } else {
             None
         }
     }
+    fn send_event(&self, event: DropdownEvent<T>) {
+        let _ = self.event_sender.send(event);
+    }
+    ///Returns true if the user typed something recently, so new characters get added to the search
+    fn is_typing_ahead(&self) -> bool {
+        !self.type_ahead.is_empty() && self.type_ahead_timer.remaining().is_some()
+    }
     fn draw_arround_rec(&self, rec: &Rectangle, gfx: &mut Graphics) {
         gfx.stroke_rect(rec, self.divider_color);
     }
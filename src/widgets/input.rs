@@ -1,14 +1,16 @@
 use crate::{
-    channels::InputChannel,
-    widgets::{widget_traits::WidgetConfig, Widget},
+    channels::{InputChannel, InputEvent},
+    widgets::{widget_traits::WidgetConfig, CompositionEvent, Widget},
     FontStyle,
 };
 use quicksilver::{
     geom::{Rectangle, Shape, Vector},
     graphics::LayoutGlyph,
     graphics::{Color, Graphics},
+    input::Key,
     Result, Timer, Window,
 };
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone)]
 pub struct PlaceholderConfig {
@@ -36,6 +38,79 @@ impl CursorConfig {
             time_off: Timer::time_per_second(2.0),
         }
     }
+
+    ///Returns if the cursor should be visible, switching between on and off once the timer for the current state runs out
+    pub(crate) fn blink(&mut self, is_visible: bool) -> bool {
+        if is_visible {
+            if self.time_on.exhaust().is_some() {
+                self.time_off.reset();
+                return false;
+            }
+            true
+        } else if self.time_off.exhaust().is_some() {
+            self.time_on.reset();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+///Decides if a character may be typed
+pub type CharFilter = Box<dyn Fn(char) -> bool>;
+///Decides if a whole value is valid
+pub type ValueValidator = Box<dyn Fn(&str) -> bool>;
+
+///Configures which characters and values an input accepts
+#[derive(Default)]
+pub struct ValidationConfig {
+    ///Only characters for which this returns true can be typed
+    pub filter: Option<CharFilter>,
+    ///The maximum amount of graphemes the value can contain
+    pub max_length: Option<usize>,
+    ///Checks if the whole value is valid.
+    ///Unlike the filter, this does not block typing. It is only used to report if the value is valid
+    pub validator: Option<ValueValidator>,
+    ///The color of the border while the value is invalid. Uses the normal border if not set
+    pub invalid_color: Option<Color>,
+}
+
+///Hides the value of an input, for example for passwords
+#[derive(Clone)]
+pub struct MaskConfig {
+    ///The character that is drawn for every grapheme of the value
+    pub character: char,
+    ///While this key is held down the real value is shown
+    pub peek_key: Option<Key>,
+}
+
+impl Default for MaskConfig {
+    fn default() -> Self {
+        Self {
+            character: '*',
+            peek_key: None,
+        }
+    }
+}
+
+///Gives a list of suggestions based on the current value of an input
+pub type SuggestionProvider = Box<dyn Fn(&str) -> Vec<String>>;
+
+///Shows a list of suggestions below an input while the user is typing
+pub struct SuggestionConfig {
+    ///Returns the suggestions for the current value.
+    ///Suggestions that the filter or max length of the input reject are ignored when picked
+    pub provider: SuggestionProvider,
+    ///The height of every suggestion inside the list
+    pub option_height: f32,
+    ///The maximum amount of suggestions that are shown at once
+    pub max_shown: usize,
+    ///The color behind every suggestion
+    pub background_color: Color,
+    ///The color behind the suggestion that is highlighted or hovered over
+    pub highlight_color: Color,
+    ///The color of the line between and arround every suggestion
+    pub divider_color: Color,
 }
 
 pub struct InputConfig {
@@ -44,26 +119,54 @@ pub struct InputConfig {
     pub location: Rectangle,
     pub start_value: Option<String>,
     pub cursor_config: CursorConfig,
+    ///Limits what the user can type and decides when the value is valid
+    pub validation: ValidationConfig,
+    ///If set, the value is drawn masked. See MaskConfig
+    pub mask: Option<MaskConfig>,
+    ///If set, a list of suggestions is shown while typing. See SuggestionConfig
+    pub suggestions: Option<SuggestionConfig>,
 }
 
 pub struct Input {
     config: InputConfig,
     value: InputChannel,
+    invalid_color: Option<Color>,
     cursor_at_from_left: usize,
     cursor_is_visible: bool,
     has_focus: bool,
+    is_peeking: bool,
+    preedit: String,
+    caret: Rectangle,
+    suggestions: Vec<String>,
+    highlighted_suggestion: Option<usize>,
+    accepted_suggestion: Option<usize>,
+    hover_over: Option<Vector>,
 }
 
 impl WidgetConfig<InputChannel, Input> for InputConfig {
-    fn to_widget(self) -> (Input, InputChannel) {
-        let value = self.start_value.clone().unwrap_or_else(|| "".into()).into();
+    fn to_widget(mut self) -> (Input, InputChannel) {
+        let validation = std::mem::take(&mut self.validation);
+        let invalid_color = validation.invalid_color;
+        let value = InputChannel::with_validation(
+            self.start_value.clone().unwrap_or_else(|| "".into()),
+            validation,
+        );
+        value.set_masked(self.mask.is_some());
         (
             Input {
                 config: self,
                 value: InputChannel::clone(&value),
+                invalid_color,
                 cursor_at_from_left: 0,
                 cursor_is_visible: true,
                 has_focus: false,
+                is_peeking: false,
+                preedit: String::new(),
+                caret: Rectangle::new(Vector::ZERO, Vector::ZERO),
+                suggestions: Vec::new(),
+                highlighted_suggestion: None,
+                accepted_suggestion: None,
+                hover_over: None,
             },
             value,
         )
@@ -72,26 +175,15 @@ impl WidgetConfig<InputChannel, Input> for InputConfig {
 
 impl Input {
     fn get_glyphs(
-        cursor_at: usize,
         gfx: &mut Graphics,
         text: &str,
         font: &FontStyle,
-        _: f32,
-    ) -> Result<(f32, f32, Vec<LayoutGlyph>)> {
+    ) -> Result<(Vec<f32>, Vec<LayoutGlyph>)> {
         let mut glyphs = Vec::new();
-        let mut length_before_cursor = 0.0;
-        let cursor_at_from_left = cursor_at;
-        font.font.layout_glyphs(gfx, &text, None, |_, glyph| {
-            if glyphs.len() + 1 == cursor_at_from_left {
-                length_before_cursor = glyph.position.x + glyph.glyph.bounds.width as f32;
-            }
-            glyphs.push(glyph);
-        })?;
-        let total_length = glyphs
-            .last()
-            .map(|glyph| glyph.position.x + glyph.glyph.bounds.width as f32)
-            .unwrap_or(0.0);
-        Ok((length_before_cursor, total_length, glyphs))
+        font.font
+            .layout_glyphs(gfx, text, None, |_, glyph| glyphs.push(glyph))?;
+        let offsets = font.font.caret_offsets(gfx, text)?;
+        Ok((offsets, glyphs))
     }
 
     fn _calc_offset(max_size: f32, current_total_size: f32, size_before_cursor: f32) -> f32 {
@@ -103,9 +195,39 @@ impl Input {
         }
         size_before_cursor - max_size
     }
+    ///Turns the value into what needs to be drawn if it is masked.
+    fn masked_text(&self, value: &str) -> Option<String> {
+        let mask = self.config.mask.as_ref()?;
+        if !self.value.is_masked() || self.is_peeking {
+            return None;
+        }
+        Some(value.graphemes(true).map(|_| mask.character).collect())
+    }
     fn draw_text(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
-        let val = self.value.get();
-        let (val, font) = if val == "" {
+        let mut val = self.value.get();
+        //the text that is still being composed is drawn in front of the cursor
+        let preedit_at = val
+            .grapheme_indices(true)
+            .nth(self.cursor_at_from_left)
+            .map(|(index, _)| index)
+            .unwrap_or_else(|| val.len());
+        val.insert_str(preedit_at, &self.preedit);
+        let preedit_graphemes = self.preedit.graphemes(true).count();
+        //the cursor counts graphemes, but the glyphs are laid out per character
+        let (val, preedit_start, cursor_at) = match self.masked_text(&val) {
+            //every grapheme is masked by a single character
+            Some(masked) => (
+                masked,
+                self.cursor_at_from_left,
+                self.cursor_at_from_left + preedit_graphemes,
+            ),
+            None => {
+                let preedit_start = val[..preedit_at].chars().count();
+                let cursor_at = preedit_start + self.preedit.chars().count();
+                (val, preedit_start, cursor_at)
+            }
+        };
+        let (val, font) = if val.is_empty() {
             match &mut self.config.placeholder {
                 Some(v) => (v.text.as_str(), &mut v.font),
                 None => ("", &mut self.config.font),
@@ -114,20 +236,13 @@ impl Input {
             (val.as_str(), &mut self.config.font)
         };
 
-        let (size_before_cursor, _, glyphs) = Self::get_glyphs(
-            self.cursor_at_from_left,
-            gfx,
-            val,
-            font,
-            self.config.location.x(),
-        )?;
+        let (offsets, glyphs) = Self::get_glyphs(gfx, val, font)?;
+        let size_before_cursor = offsets.get(cursor_at).copied().unwrap_or(0.0);
         glyphs.iter().for_each(|layout_glyph| {
             let glyph_bounds = layout_glyph.glyph.bounds;
             let pos = Vector::new(
                 layout_glyph.position.x + self.config.location.pos.x,
-                layout_glyph.position.y as f32
-                    + self.config.font.font.size
-                    + self.config.location.pos.y,
+                layout_glyph.position.y + self.config.font.font.size + self.config.location.pos.y,
             );
 
             let glyph_size = Vector::new(glyph_bounds.width as f32, glyph_bounds.height as f32);
@@ -143,47 +258,173 @@ impl Input {
                 self.config.font.color,
             );
         });
+        if !self.preedit.is_empty() {
+            let start = offsets.get(preedit_start).copied().unwrap_or(0.0);
+            gfx.fill_rect(
+                &Rectangle::new(
+                    Vector::new(
+                        self.config.location.pos.x + start,
+                        self.config.location.pos.y + self.config.font.font.size + 2.0,
+                    ),
+                    Vector::new(size_before_cursor - start, 1.0),
+                ),
+                self.config.font.color,
+            );
+        }
+        self.caret = Rectangle::new(
+            Vector::new(
+                self.config.location.pos.x + size_before_cursor,
+                self.config.location.pos.y,
+            ),
+            Vector::new(
+                self.config.cursor_config.thickness,
+                self.config.location.size.y,
+            ),
+        );
         if !self.has_focus {
             return Ok(());
         }
+        self.cursor_is_visible = self.config.cursor_config.blink(self.cursor_is_visible);
         if self.cursor_is_visible {
-            if self.config.cursor_config.time_on.exhaust().is_some() {
-                self.cursor_is_visible = false;
-                self.config.cursor_config.time_off.reset();
+            gfx.fill_rect(&self.caret, self.config.cursor_config.color);
+        }
+        Ok(())
+    }
+
+    ///Sends the change event and updates the suggestions if the user changed the value
+    fn value_changed(&mut self, old_value: &str) {
+        if self.value.get() == old_value {
+            return;
+        }
+        self.value.send_if_changed(old_value);
+        self.update_suggestions();
+    }
+
+    ///Asks the provider for new suggestions based on the current value
+    pub(crate) fn update_suggestions(&mut self) {
+        self.highlighted_suggestion = None;
+        self.suggestions = match &self.config.suggestions {
+            Some(config) => {
+                let mut suggestions = (config.provider)(&self.value.get());
+                suggestions.truncate(config.max_shown);
+                suggestions
+            }
+            None => Vec::new(),
+        };
+    }
+
+    pub(crate) fn close_suggestions(&mut self) {
+        self.suggestions.clear();
+        self.highlighted_suggestion = None;
+    }
+
+    pub(crate) fn has_suggestions(&self) -> bool {
+        !self.suggestions.is_empty()
+    }
+
+    ///Returns the index of the suggestion that the user accepted since the last time this got called
+    pub(crate) fn take_accepted_suggestion(&mut self) -> Option<usize> {
+        self.accepted_suggestion.take()
+    }
+
+    pub(crate) fn has_highlighted_suggestion(&self) -> bool {
+        self.highlighted_suggestion.is_some()
+    }
+
+    fn accept_suggestion(&mut self, index: usize) {
+        if let Some(suggestion) = self.suggestions.get(index).cloned() {
+            let old_value = self.value.get();
+            //suggestions that the filter or max length don't allow are ignored
+            if self.value.try_set(suggestion) {
+                self.cursor_at_from_left = self.value.grapheme_count();
+                self.value.send_if_changed(&old_value);
+                self.accepted_suggestion = Some(index);
             }
-        } else if self.config.cursor_config.time_off.exhaust().is_some() {
-            self.cursor_is_visible = true;
-            self.config.cursor_config.time_on.reset();
         }
-        if self.cursor_is_visible {
+        self.close_suggestions();
+    }
+
+    fn get_suggestion_rec(&self, index: usize) -> Option<Rectangle> {
+        let config = self.config.suggestions.as_ref()?;
+        let mut rec = self.config.location;
+        rec.pos.y += rec.size.y + config.option_height * index as f32;
+        rec.size.y = config.option_height;
+        Some(rec)
+    }
+
+    fn suggestion_at(&self, point: Vector) -> Option<usize> {
+        (0..self.suggestions.len()).find(|index| {
+            self.get_suggestion_rec(*index)
+                .map(|rec| rec.contains(point))
+                .unwrap_or(false)
+        })
+    }
+
+    fn draw_suggestions(&self, gfx: &mut Graphics) -> Result<()> {
+        let config = match &self.config.suggestions {
+            Some(config) if self.has_focus => config,
+            _ => return Ok(()),
+        };
+        let hovered = self.hover_over.and_then(|v| self.suggestion_at(v));
+        for (index, suggestion) in self.suggestions.iter().enumerate() {
+            let rec = match self.get_suggestion_rec(index) {
+                Some(rec) => rec,
+                None => continue,
+            };
+            let is_highlighted =
+                self.highlighted_suggestion == Some(index) || hovered == Some(index);
             gfx.fill_rect(
-                &Rectangle::new(
-                    Vector::new(
-                        self.config.location.pos.x + size_before_cursor,
-                        self.config.location.pos.y,
-                    ),
-                    Vector::new(
-                        self.config.cursor_config.thickness,
-                        self.config.location.size.y,
-                    ),
-                ),
-                self.config.cursor_config.color,
+                &rec,
+                if is_highlighted {
+                    config.highlight_color
+                } else {
+                    config.background_color
+                },
             );
+            gfx.stroke_rect(&rec, config.divider_color);
+            let mut pos = rec.pos;
+            pos.y += self.config.font.font.size;
+            self.config
+                .font
+                .font
+                .draw(gfx, suggestion, self.config.font.color, pos)?;
         }
         Ok(())
     }
+
+    fn insert(&mut self, text: &str) {
+        self.cursor_at_from_left = self.cursor_at_from_left.min(self.value.grapheme_count());
+        if let Some(cursor) = self.value.type_at_grapheme(self.cursor_at_from_left, text) {
+            self.cursor_at_from_left = cursor;
+            self.update_suggestions();
+        }
+    }
 }
 
 impl Widget for Input {
     fn contains(&self, pos: Vector) -> bool {
         self.config.location.contains(Vector::new(pos.x, pos.y))
+            || (self.has_focus && self.suggestion_at(pos).is_some())
     }
     fn is_focusable(&self, _: Vector) -> bool {
         true
     }
     fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
-        gfx.stroke_rect(&self.config.location, Color::BLACK);
-        self.draw_text(gfx, window)
+        let border_color = match self.invalid_color {
+            Some(color) if !self.value.is_valid() => color,
+            _ => Color::BLACK,
+        };
+        gfx.stroke_rect(&self.config.location, border_color);
+        self.draw_text(gfx, window)?;
+        self.draw_suggestions(gfx)
+    }
+    fn set_hover(&mut self, point: Vector, state: bool) {
+        self.hover_over = if state { Some(point) } else { None };
+    }
+    fn on_click(&mut self, location: Vector) {
+        if let Some(index) = self.suggestion_at(location) {
+            self.accept_suggestion(index);
+        }
     }
     fn set_focus(&mut self, _: Vector, focus: bool) {
         if focus {
@@ -192,6 +433,16 @@ impl Widget for Input {
             self.cursor_is_visible = true;
         } else {
             self.cursor_is_visible = false;
+            self.is_peeking = false;
+            self.preedit.clear();
+            self.close_suggestions();
+        }
+        if focus != self.has_focus {
+            self.value.send_event(if focus {
+                InputEvent::FocusGained
+            } else {
+                InputEvent::FocusLost
+            });
         }
         self.has_focus = focus
     }
@@ -201,16 +452,52 @@ impl Widget for Input {
 
     fn on_key_press(&mut self, key: quicksilver::input::Key, state: bool) {
         use quicksilver::input::Key::*;
+        //the peek key can be released while composing, so it is tracked before anything else
+        if let Some(peek_key) = self.config.mask.as_ref().and_then(|v| v.peek_key) {
+            if key == peek_key {
+                self.is_peeking = state;
+            }
+        }
+        //while composing, the keys are used by the input method
+        if !self.preedit.is_empty() {
+            return;
+        }
+        //the value can be changed through the channel, so make sure the cursor is still inside of it
+        self.cursor_at_from_left = self.cursor_at_from_left.min(self.value.grapheme_count());
+        if !self.suggestions.is_empty() && state {
+            let count = self.suggestions.len();
+            match (key, self.highlighted_suggestion) {
+                (Down, None) | (Tab, None) => self.highlighted_suggestion = Some(0),
+                (Down, Some(current)) | (Tab, Some(current)) => {
+                    self.highlighted_suggestion = Some((current + 1) % count)
+                }
+                (Up, None) => self.highlighted_suggestion = Some(count - 1),
+                (Up, Some(current)) => {
+                    self.highlighted_suggestion = Some((current + count - 1) % count)
+                }
+                (Return, Some(current)) | (NumpadEnter, Some(current)) => {
+                    return self.accept_suggestion(current)
+                }
+                (Escape, _) => self.close_suggestions(),
+                _ => {}
+            }
+        }
         if Back == key && state && self.cursor_at_from_left > 0 {
-            let current_char_count = self.value.char_count();
-            self.value.remove_char_at(self.cursor_at_from_left - 1);
-            self.cursor_at_from_left -= current_char_count - self.value.char_count();
+            let old_value = self.value.get();
+            let current_count = self.value.grapheme_count();
+            self.value.remove_grapheme_at(self.cursor_at_from_left - 1);
+            self.cursor_at_from_left -= current_count - self.value.grapheme_count();
+            self.value_changed(&old_value);
+        }
+        if (key == Return || key == NumpadEnter) && state {
+            self.close_suggestions();
+            self.value.submit();
         }
         if key == Left && state && self.cursor_at_from_left > 0 {
             self.cursor_at_from_left -= 1;
         }
         if key == Right && state {
-            let size = self.value.char_count();
+            let size = self.value.grapheme_count();
             if self.cursor_at_from_left < size {
                 self.cursor_at_from_left += 1;
             }
@@ -218,16 +505,30 @@ impl Widget for Input {
     }
 
     fn on_typed(&mut self, typed_char: char) {
-        if typed_char.is_control() {
+        if typed_char.is_control() || !self.preedit.is_empty() {
             return;
         }
-        let old_count = self.value.char_count();
-        if self.cursor_at_from_left == old_count {
-            self.value.push(typed_char)
+        self.insert(typed_char.encode_utf8(&mut [0; 4]));
+    }
+    fn on_composition(&mut self, event: &CompositionEvent) {
+        match event {
+            CompositionEvent::Start => self.preedit.clear(),
+            CompositionEvent::Update(preedit) => self.preedit = preedit.clone(),
+            CompositionEvent::Commit(text) => {
+                self.preedit.clear();
+                let text: String = text
+                    .chars()
+                    .filter(|character| !character.is_control())
+                    .collect();
+                self.insert(&text);
+            }
+        }
+    }
+    fn get_caret_rect(&self) -> Option<Rectangle> {
+        if self.has_focus {
+            Some(self.caret)
         } else {
-            self.value
-                .insert_char_at_place(self.cursor_at_from_left, typed_char)
-        };
-        self.cursor_at_from_left += self.value.char_count() - old_count;
+            None
+        }
     }
 }
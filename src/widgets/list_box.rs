@@ -0,0 +1,479 @@
+use super::{
+    dropdown::{DropDownValueConfig, SCROLLBAR_WIDTH},
+    Widget, WidgetConfig,
+};
+use crate::channels::{ListBoxChannel, ListBoxEvent};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::{Key, ScrollDelta},
+    Result, Window,
+};
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    marker::PhantomData,
+    rc::Rc,
+    sync::mpsc::{self, Sender},
+};
+
+///Configures a list box. A list that is always open where multiple values can be selected
+///
+///Hold control to add or remove a single value from the selection and shift to select a range.
+pub struct ListBoxConfig<T: Clone, ValueConfig: Into<DropDownValueConfig<T>>> {
+    ///A list of selectable values and how to render them. See DropDownValueConfig
+    pub values: Vec<ValueConfig>,
+    ///The location of this widget. The list scrolls if not every value fits
+    pub location: Rectangle,
+    ///The height of every value inside the list
+    pub option_height: f32,
+    ///What starts as selected
+    pub selected: Vec<usize>,
+    ///The color behind selected values
+    pub selected_color: Color,
+    ///Draws a checkbox in front of every value. Clicking on it toggles the value without changing the rest of the selection
+    pub show_checkboxes: bool,
+    ///The color of the line between and arround every value
+    pub divider_color: Color,
+    ///The width of the line between and arround every value
+    pub divider_size: f32,
+    pub t: PhantomData<T>,
+}
+
+pub struct ListBox<T: Clone> {
+    pub location: Rectangle,
+    pub option_height: f32,
+    pub values: Rc<RefCell<Vec<DropDownValueConfig<T>>>>,
+    pub selected: Rc<RefCell<BTreeSet<usize>>>,
+    pub selected_color: Color,
+    pub show_checkboxes: bool,
+    pub divider_color: Color,
+    pub divider_size: f32,
+    pub hover_over: Option<Vector>,
+    ///The index of the first value that is visible
+    pub scroll_offset: usize,
+    ///The value that the keyboard is at
+    pub cursor: Option<usize>,
+    scroll_remainder: f32,
+    drag_start: Option<(f32, usize)>,
+    anchor: Option<usize>,
+    values_version: Rc<RefCell<usize>>,
+    seen_values_version: usize,
+    shift_down: bool,
+    control_down: bool,
+    event_sender: Sender<ListBoxEvent<T>>,
+}
+
+impl<T: Clone, X: Into<DropDownValueConfig<T>>> WidgetConfig<ListBoxChannel<T>, ListBox<T>>
+    for ListBoxConfig<T, X>
+{
+    fn to_widget(self) -> (ListBox<T>, ListBoxChannel<T>) {
+        let values: Vec<DropDownValueConfig<T>> = self.values.into_iter().map(Into::into).collect();
+        let selected: BTreeSet<usize> = self
+            .selected
+            .into_iter()
+            .filter(|index| {
+                values
+                    .get(*index)
+                    .map(|value| value.is_selectable())
+                    .unwrap_or(false)
+            })
+            .collect();
+        let values = Rc::new(RefCell::new(values));
+        let selected = Rc::new(RefCell::new(selected));
+        let values_version = Rc::new(RefCell::new(0));
+        let (event_sender, events) = mpsc::channel();
+        (
+            ListBox {
+                location: self.location,
+                option_height: self.option_height,
+                values: values.clone(),
+                selected: selected.clone(),
+                selected_color: self.selected_color,
+                show_checkboxes: self.show_checkboxes,
+                divider_color: self.divider_color,
+                divider_size: self.divider_size,
+                hover_over: None,
+                scroll_offset: 0,
+                cursor: None,
+                scroll_remainder: 0.0,
+                drag_start: None,
+                anchor: None,
+                values_version: values_version.clone(),
+                seen_values_version: 0,
+                shift_down: false,
+                control_down: false,
+                event_sender,
+            },
+            ListBoxChannel {
+                selected,
+                values,
+                values_version,
+                events,
+            },
+        )
+    }
+}
+
+impl<T: Clone> Widget for ListBox<T> {
+    fn contains(&self, point: Vector) -> bool {
+        self.location.contains(point)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn set_hover(&mut self, point: Vector, state: bool) {
+        self.hover_over = if state { Some(point) } else { None };
+    }
+    fn set_focus(&mut self, _: Vector, focus: bool) {
+        if !focus {
+            self.shift_down = false;
+            self.control_down = false;
+        }
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        self.sync_values();
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+        let hovered = self.hover_over.and_then(|v| self.vector_to_index(v));
+        let values = self.values.borrow();
+        let selected = self.selected.borrow();
+        for (row, (index, value)) in values
+            .iter()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .take(self.visible_count())
+            .enumerate()
+        {
+            let location = self.get_row_rec(row);
+            let is_selected = selected.contains(&index);
+            if is_selected {
+                gfx.fill_rect(&location, self.selected_color);
+            }
+            let mut content = location;
+            if self.show_checkboxes && value.is_selectable() {
+                let checkbox = self.get_checkbox_rec(location);
+                gfx.stroke_rect(&checkbox, self.divider_color);
+                if is_selected {
+                    let mut check = checkbox;
+                    check.pos += checkbox.size / 4.0;
+                    check.size /= 2.0;
+                    gfx.fill_rect(&check, self.divider_color);
+                }
+            }
+            if self.show_checkboxes {
+                content.pos.x += self.option_height;
+                content.size.x -= self.option_height;
+            }
+            let font_style = match &value.hover_font_style {
+                Some(hover)
+                    if value.is_selectable()
+                        && (hovered == Some(index) || self.cursor == Some(index)) =>
+                {
+                    hover
+                }
+                _ => &value.normal_font_style,
+            };
+            value.draw(
+                gfx,
+                font_style,
+                content,
+                self.divider_color,
+                self.divider_size,
+            )?;
+        }
+        drop(values);
+        drop(selected);
+        if let Some(track) = self.get_scrollbar_rec() {
+            let len = self.values.borrow().len() as f32;
+            let mut thumb = track;
+            thumb.size.y = track.height() * self.visible_count() as f32 / len;
+            thumb.pos.y += track.height() * self.scroll_offset as f32 / len;
+            gfx.fill_rect(&thumb, self.divider_color);
+            gfx.stroke_rect(&track, self.divider_color);
+        }
+        gfx.stroke_rect(&self.location, self.divider_color);
+        Ok(())
+    }
+    fn on_click(&mut self, pos: Vector) {
+        self.sync_values();
+        if self.get_scrollbar_rec().map(|track| track.contains(pos)) == Some(true) {
+            self.drag_start = Some((pos.y, self.scroll_offset));
+            return;
+        }
+        let index = match self.vector_to_index(pos) {
+            Some(index) if self.is_selectable(index) => index,
+            _ => return,
+        };
+        let on_checkbox = self.show_checkboxes
+            && self
+                .get_checkbox_rec(self.get_row_rec(index - self.scroll_offset))
+                .contains(pos);
+        if on_checkbox {
+            self.cursor = Some(index);
+            self.anchor = Some(index);
+            self.change_selection(|selected| toggle(selected, index));
+        } else {
+            self.select_with_modifiers(index);
+        }
+    }
+    fn on_drag(&mut self, pos: Vector) {
+        if let (Some((start_y, start_offset)), Some(track)) =
+            (self.drag_start, self.get_scrollbar_rec())
+        {
+            let len = self.values.borrow().len() as f32;
+            let moved = (pos.y - start_y) / track.height() * len;
+            let offset = (start_offset as f32 + moved).round().max(0.0) as usize;
+            self.scroll_offset = offset.min(self.max_scroll_offset());
+        }
+    }
+    fn on_release(&mut self, _: Vector) {
+        self.drag_start = None;
+    }
+    fn on_scroll(&mut self, delta: &ScrollDelta) {
+        let options = match delta {
+            ScrollDelta::Lines(amount) => -amount.y,
+            ScrollDelta::Pixels(amount) => -amount.y / self.option_height,
+        };
+        self.scroll_remainder += options;
+        let whole_options = self.scroll_remainder.trunc();
+        self.scroll_remainder -= whole_options;
+        let offset = (self.scroll_offset as f32 + whole_options).max(0.0) as usize;
+        self.scroll_offset = offset.min(self.max_scroll_offset());
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        self.sync_values();
+        match key {
+            Key::LShift | Key::RShift => self.shift_down = state,
+            Key::LControl | Key::RControl => self.control_down = state,
+            _ => {}
+        }
+        if !state {
+            return;
+        }
+        match key {
+            Key::Down | Key::Up => {
+                if let Some(next) = self.next_selectable(key == Key::Down) {
+                    if self.control_down {
+                        self.cursor = Some(next);
+                    } else {
+                        self.select_with_modifiers(next);
+                    }
+                    self.scroll_to(next);
+                }
+            }
+            Key::Space => {
+                if let Some(cursor) = self.cursor.filter(|cursor| self.is_selectable(*cursor)) {
+                    self.anchor = Some(cursor);
+                    self.change_selection(|selected| toggle(selected, cursor));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<T: Clone> ListBox<T> {
+    ///The amount of values that fit inside the list
+    pub fn visible_count(&self) -> usize {
+        let len = self.values.borrow().len();
+        let fits = (self.location.height() / self.option_height)
+            .floor()
+            .max(1.0) as usize;
+        fits.min(len)
+    }
+    fn max_scroll_offset(&self) -> usize {
+        self.values.borrow().len() - self.visible_count()
+    }
+    ///Scrolls the list so the given value is visible
+    pub fn scroll_to(&mut self, index: usize) {
+        let visible = self.visible_count();
+        if index < self.scroll_offset {
+            self.scroll_offset = index;
+        } else if index >= self.scroll_offset + visible {
+            self.scroll_offset = index + 1 - visible;
+        }
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll_offset());
+    }
+    ///The location of the scrollbar. Returns None if every value fits
+    pub fn get_scrollbar_rec(&self) -> Option<Rectangle> {
+        if self.max_scroll_offset() == 0 {
+            return None;
+        }
+        Some(Rectangle::new(
+            Vector::new(
+                self.location.pos.x + self.location.width() - SCROLLBAR_WIDTH,
+                self.location.pos.y,
+            ),
+            Vector::new(
+                SCROLLBAR_WIDTH,
+                self.option_height * self.visible_count() as f32,
+            ),
+        ))
+    }
+    fn get_row_rec(&self, row: usize) -> Rectangle {
+        let mut location = self.location;
+        location.pos.y += self.option_height * row as f32;
+        location.size.y = self.option_height;
+        location
+    }
+    fn get_checkbox_rec(&self, row: Rectangle) -> Rectangle {
+        let size = self.option_height / 2.0;
+        Rectangle::new(
+            row.pos + Vector::new(size / 2.0, size / 2.0),
+            Vector::new(size, size),
+        )
+    }
+    pub fn vector_to_index(&self, point: Vector) -> Option<usize> {
+        if !self.location.contains(point)
+            || self.get_scrollbar_rec().map(|track| track.contains(point)) == Some(true)
+        {
+            return None;
+        }
+        let row = ((point.y - self.location.pos.y) / self.option_height).floor() as usize;
+        if row < self.visible_count() {
+            Some(row + self.scroll_offset)
+        } else {
+            None
+        }
+    }
+    fn is_selectable(&self, index: usize) -> bool {
+        self.values
+            .borrow()
+            .get(index)
+            .map(|value| value.is_selectable())
+            .unwrap_or(false)
+    }
+    ///Finds the next value after the cursor that can be selected
+    fn next_selectable(&self, forward: bool) -> Option<usize> {
+        let len = self.values.borrow().len() as isize;
+        let start = match self.cursor {
+            Some(cursor) => cursor as isize,
+            None if forward => -1,
+            None => len,
+        };
+        (1..=len)
+            .map(|step| if forward { start + step } else { start - step })
+            .take_while(|index| (0..len).contains(index))
+            .map(|index| index as usize)
+            .find(|index| self.is_selectable(*index))
+    }
+    ///Forgets the keyboard position and the scroll position once the values got replaced through the channel
+    fn sync_values(&mut self) {
+        let version = *self.values_version.borrow();
+        if version != self.seen_values_version {
+            self.seen_values_version = version;
+            self.cursor = None;
+            self.anchor = None;
+            self.scroll_offset = 0;
+        }
+    }
+    ///Selects the given value like a click would, taking shift and control into account
+    fn select_with_modifiers(&mut self, index: usize) {
+        if !self.is_selectable(index) {
+            return;
+        }
+        self.cursor = Some(index);
+        match (self.shift_down, self.anchor) {
+            (true, Some(anchor)) => {
+                let range = anchor.min(index)..=anchor.max(index);
+                let keep_rest = self.control_down;
+                let values = self.values.clone();
+                self.change_selection(|selected| {
+                    if !keep_rest {
+                        selected.clear();
+                    }
+                    let values = values.borrow();
+                    selected.extend(range.filter(|index| {
+                        values
+                            .get(*index)
+                            .map(|value| value.is_selectable())
+                            .unwrap_or(false)
+                    }));
+                });
+            }
+            _ => {
+                self.anchor = Some(index);
+                if self.control_down {
+                    self.change_selection(|selected| toggle(selected, index));
+                } else {
+                    self.change_selection(|selected| {
+                        selected.clear();
+                        selected.insert(index);
+                    });
+                }
+            }
+        }
+    }
+    ///Changes the selection and sends an event if it changed
+    fn change_selection(&mut self, change: impl FnOnce(&mut BTreeSet<usize>)) {
+        let old = self.selected.borrow().clone();
+        change(&mut self.selected.borrow_mut());
+        if *self.selected.borrow() != old {
+            let values = self.values.borrow();
+            let selected = self
+                .selected
+                .borrow()
+                .iter()
+                .filter_map(|index| values.get(*index))
+                .map(|value| value.value.clone())
+                .collect();
+            let _ = self.event_sender.send(ListBoxEvent::Changed(selected));
+        }
+    }
+}
+
+fn toggle(selected: &mut BTreeSet<usize>, index: usize) {
+    if !selected.remove(&index) {
+        selected.insert(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///A list box that the user used with the keyboard and mouse before, leaving the anchor and cursor at the last value
+    fn used_list_box() -> (ListBox<u32>, ListBoxChannel<u32>) {
+        let (mut list_box, channel) = ListBoxConfig::<u32, DropDownValueConfig<u32>> {
+            values: Vec::new(),
+            location: Rectangle::new(Vector::ZERO, Vector::new(100., 100.)),
+            option_height: 20.,
+            selected: Vec::new(),
+            selected_color: Color::BLUE,
+            show_checkboxes: false,
+            divider_color: Color::BLACK,
+            divider_size: 1.,
+            t: PhantomData,
+        }
+        .to_widget();
+        list_box.anchor = Some(5);
+        list_box.cursor = Some(5);
+        (list_box, channel)
+    }
+
+    #[test]
+    fn shift_click_after_set_values() {
+        let (mut list_box, mut channel) = used_list_box();
+        channel.set_values(Vec::<DropDownValueConfig<u32>>::new());
+        list_box.on_key_press(Key::LShift, true);
+        list_box.on_click(Vector::new(50., 10.));
+        list_box.select_with_modifiers(0);
+        assert_eq!(list_box.anchor, None);
+        assert!(channel.get_selected().is_empty());
+        assert!(channel.events().is_empty());
+    }
+
+    #[test]
+    fn keyboard_after_set_values() {
+        let (mut list_box, mut channel) = used_list_box();
+        channel.set_values(Vec::<DropDownValueConfig<u32>>::new());
+        list_box.on_key_press(Key::Space, true);
+        list_box.on_key_press(Key::Down, true);
+        assert_eq!(list_box.cursor, None);
+        assert!(channel.get_selected().is_empty());
+        assert!(channel.events().is_empty());
+    }
+}
@@ -0,0 +1,315 @@
+use super::{
+    context_menu::ContextMenu, ContextMenuConfig, ContextMenuItem, GlobalClick, Widget,
+    WidgetConfig,
+};
+use crate::{channels::MenuBarChannel, LayerId, MFont};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::Key,
+    Result, Window,
+};
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
+
+///A single menu of a menu bar, like "File" or "Edit"
+pub struct MenuBarEntry<T> {
+    ///The text shown in the bar
+    pub title: String,
+    ///The items of the menu that opens below the title. See ContextMenuItem
+    pub items: Vec<ContextMenuItem<T>>,
+}
+
+///Configures a menu bar. A bar with titles where clicking on one opens its menu below it.
+///While a menu is open, hovering over another title opens that menu instead
+///
+///Add it with `Context::add_menu_bar`, which draws the menus on a layer above every other layer
+pub struct MenuBarConfig<T> {
+    pub entries: Vec<MenuBarEntry<T>>,
+    ///Where the bar is drawn. The titles are placed from left to right
+    pub location: Rectangle,
+    ///The width of every title in the bar
+    pub title_width: f32,
+    ///The size of every item in the menus
+    pub item_size: Vector,
+    pub font: MFont,
+    pub text_color: Color,
+    pub background_color: Color,
+    ///The color drawn behind the title of the open menu and the highlighted items
+    pub highlight_color: Color,
+    pub border_color: Color,
+}
+
+///What the bar and the widget that draws its menus share
+struct MenuBarState<T> {
+    menus: Vec<ContextMenu<T>>,
+    open: Rc<RefCell<Option<usize>>>,
+    location: Rectangle,
+    title_width: f32,
+}
+
+impl<T: Clone> MenuBarState<T> {
+    fn get_title_rec(&self, index: usize) -> Rectangle {
+        Rectangle::new(
+            self.location.pos + Vector::new(self.title_width * index as f32, 0.),
+            Vector::new(self.title_width, self.location.height()),
+        )
+    }
+    fn vector_to_title(&self, point: Vector) -> Option<usize> {
+        (0..self.menus.len()).find(|index| self.get_title_rec(*index).contains(point))
+    }
+    fn get_open(&self) -> Option<usize> {
+        *self.open.borrow()
+    }
+    fn get_open_menu(&mut self) -> Option<&mut ContextMenu<T>> {
+        let open = self.get_open()?;
+        self.menus.get_mut(open)
+    }
+    fn menu_contains(&self, point: Vector) -> bool {
+        self.get_open()
+            .map(|index| self.menus[index].contains(point))
+            .unwrap_or(false)
+    }
+    ///Opens the given menu below its title and closes the one that was open
+    fn open_menu(&mut self, new_open: Option<usize>) {
+        if let Some(menu) = self.get_open_menu() {
+            menu.is_open.replace(false);
+        }
+        self.open.replace(new_open);
+        if let Some(index) = new_open {
+            let title = self.get_title_rec(index);
+            self.menus[index].open_at(title.pos + Vector::new(0., title.height()));
+        }
+    }
+    ///Makes sure that only the menu the channel thinks is open is open.
+    ///Menus close themselves when something is chosen and the channel can close them as well
+    fn sync_open(&mut self) {
+        let open = self.get_open();
+        for (index, menu) in self.menus.iter_mut().enumerate() {
+            if Some(index) != open && menu.is_open() {
+                menu.is_open.replace(false);
+            }
+        }
+        if let Some(index) = open {
+            if !self.menus[index].is_open() {
+                self.open.replace(None);
+            }
+        }
+    }
+    ///Focus moving to the menus isn't a reason to close them, so this only closes the menu if the point is outside of it
+    fn lose_focus(&mut self, point: Vector) {
+        if !self.menu_contains(point) {
+            if let Some(menu) = self.get_open_menu() {
+                menu.close();
+            }
+            self.sync_open();
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        let open = match self.get_open() {
+            Some(open) => open,
+            None => return,
+        };
+        let len = self.menus.len();
+        let depth = self.menus[open].depth();
+        //left and right go to the previous or next menu, unless they are used for submenus
+        match key {
+            Key::Left if state && depth == 1 => self.open_menu(Some((open + len - 1) % len)),
+            Key::Right if state && !self.menus[open].has_submenu_highlighted() => {
+                self.open_menu(Some((open + 1) % len))
+            }
+            _ => {
+                self.menus[open].on_key_press(key, state);
+                self.sync_open();
+            }
+        }
+    }
+}
+
+///The bar with the titles. The open menu is drawn by MenuBarMenus, on a layer above the bar
+pub struct MenuBar<T> {
+    state: Rc<RefCell<MenuBarState<T>>>,
+    titles: Vec<String>,
+    pub font: MFont,
+    pub text_color: Color,
+    pub background_color: Color,
+    pub highlight_color: Color,
+    hover_over: Option<usize>,
+}
+
+///Draws the open menu of a menu bar
+pub struct MenuBarMenus<T> {
+    state: Rc<RefCell<MenuBarState<T>>>,
+}
+
+impl<T: Clone> MenuBarConfig<T> {
+    ///Creates the bar and the widget that draws its menus.
+    ///The channel keeps the layer of the menus alive
+    pub(crate) fn into_widgets(
+        self,
+        menus_layer: LayerId,
+    ) -> (MenuBar<T>, MenuBarMenus<T>, MenuBarChannel<T>) {
+        let (event_sender, events) = mpsc::channel();
+        let mut titles = Vec::new();
+        let mut menus = Vec::new();
+        for entry in self.entries {
+            titles.push(entry.title);
+            let (mut menu, _) = ContextMenuConfig {
+                items: entry.items,
+                location: None,
+                item_size: self.item_size,
+                font: self.font.clone(),
+                text_color: self.text_color,
+                background_color: self.background_color,
+                highlight_color: self.highlight_color,
+                border_color: self.border_color,
+            }
+            .to_widget();
+            //every menu sends its events to the same channel
+            menu.event_sender = event_sender.clone();
+            menu.is_open.replace(false);
+            menus.push(menu);
+        }
+        let open = Rc::new(RefCell::new(None));
+        let state = Rc::new(RefCell::new(MenuBarState {
+            menus,
+            open: open.clone(),
+            location: self.location,
+            title_width: self.title_width,
+        }));
+        (
+            MenuBar {
+                state: state.clone(),
+                titles,
+                font: self.font,
+                text_color: self.text_color,
+                background_color: self.background_color,
+                highlight_color: self.highlight_color,
+                hover_over: None,
+            },
+            MenuBarMenus { state },
+            MenuBarChannel {
+                open,
+                events,
+                _menus_layer: menus_layer,
+            },
+        )
+    }
+}
+
+impl<T: Clone> MenuBar<T> {
+    pub fn get_title_rec(&self, index: usize) -> Rectangle {
+        self.state.borrow().get_title_rec(index)
+    }
+}
+
+impl<T: Clone> Widget for MenuBar<T> {
+    fn contains(&self, point: Vector) -> bool {
+        self.state.borrow().location.contains(point)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        state.sync_open();
+        gfx.fill_rect(&state.location, self.background_color);
+        let open = state.get_open();
+        for (index, title) in self.titles.iter().enumerate() {
+            let location = state.get_title_rec(index);
+            if open == Some(index) || self.hover_over == Some(index) {
+                gfx.fill_rect(&location, self.highlight_color);
+            }
+            let text_pos = Vector::new(
+                location.pos.x + location.height() / 2.0,
+                location.pos.y + self.font.size,
+            );
+            self.font.draw(gfx, title, self.text_color, text_pos)?;
+        }
+        Ok(())
+    }
+    fn set_hover(&mut self, point: Vector, state: bool) {
+        let mut bar = self.state.borrow_mut();
+        self.hover_over = if state {
+            bar.vector_to_title(point)
+        } else {
+            None
+        };
+        //while a menu is open, hovering over another title switches to its menu
+        match (self.hover_over, bar.get_open()) {
+            (Some(hovered), Some(open)) if hovered != open => bar.open_menu(Some(hovered)),
+            _ => {}
+        }
+    }
+    fn set_focus(&mut self, point: Vector, focus: bool) {
+        if !focus {
+            self.state.borrow_mut().lose_focus(point);
+        }
+    }
+    fn on_click(&mut self, point: Vector) {
+        let mut state = self.state.borrow_mut();
+        match state.vector_to_title(point) {
+            Some(index) if state.get_open() == Some(index) => {
+                state.menus[index].close();
+                state.sync_open();
+            }
+            Some(index) => state.open_menu(Some(index)),
+            None => {}
+        }
+    }
+    fn on_global_click(&mut self, click: &GlobalClick) {
+        let mut state = self.state.borrow_mut();
+        if state.location.contains(click.location) {
+            return;
+        }
+        if let Some(menu) = state.get_open_menu() {
+            menu.on_global_click(click);
+        }
+        state.sync_open();
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        self.state.borrow_mut().on_key_press(key, state);
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
+
+impl<T: Clone> Widget for MenuBarMenus<T> {
+    fn contains(&self, point: Vector) -> bool {
+        self.state.borrow().menu_contains(point)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        state.sync_open();
+        if let Some(menu) = state.get_open_menu() {
+            menu.render(gfx, window)?;
+        }
+        Ok(())
+    }
+    fn set_hover(&mut self, point: Vector, hover: bool) {
+        if let Some(menu) = self.state.borrow_mut().get_open_menu() {
+            menu.set_hover(point, hover);
+        }
+    }
+    fn set_focus(&mut self, point: Vector, focus: bool) {
+        if !focus {
+            self.state.borrow_mut().lose_focus(point);
+        }
+    }
+    fn on_click(&mut self, point: Vector) {
+        let mut state = self.state.borrow_mut();
+        if let Some(menu) = state.get_open_menu() {
+            menu.on_click(point);
+        }
+        state.sync_open();
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        self.state.borrow_mut().on_key_press(key, state);
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
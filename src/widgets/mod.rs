@@ -1,13 +1,41 @@
+pub mod accordion;
 pub mod button;
+pub mod checkbox;
+pub mod combo_box;
 pub mod concealer;
 pub mod concealer_manager;
+pub mod context_menu;
 pub mod dropdown;
 pub mod input;
+pub mod list_box;
+pub mod menu_bar;
+pub mod progress_bar;
+pub mod radio_group;
+pub mod slider;
+pub mod spin_box;
+pub mod tab_view;
+pub mod text_area;
+pub mod toggle;
 mod widget_traits;
 
+pub use accordion::{AccordionConfig, AccordionSection};
 pub use button::ButtonConfig;
+pub use checkbox::{CheckboxConfig, CheckboxLook, LabelConfig};
+pub use combo_box::ComboBoxConfig;
 pub use concealer::ConcealerConfig;
 pub use concealer_manager::ConcealerManagerConfig;
-pub use dropdown::{DropDownConfig, DropDownValueConfig};
-pub use input::{CursorConfig, InputConfig};
-pub use widget_traits::{Widget, WidgetConfig};
+pub use context_menu::{ContextMenuConfig, ContextMenuItem};
+pub use dropdown::{DropDownConfig, DropDownEntryKind, DropDownValueConfig, OpenDirection};
+pub use input::{CursorConfig, InputConfig, MaskConfig, SuggestionConfig, ValidationConfig};
+pub use list_box::ListBoxConfig;
+pub use menu_bar::{MenuBarConfig, MenuBarEntry};
+pub use progress_bar::{
+    BarAnimation, FillDirection, NineSlice, ProgressBarConfig, ProgressBarLook, ProgressBarText,
+};
+pub use radio_group::{RadioGroupConfig, RadioLayout};
+pub use slider::{SliderConfig, SliderLook, SliderOrientation};
+pub use spin_box::SpinBoxConfig;
+pub use tab_view::{TabConfig, TabViewConfig};
+pub use text_area::TextAreaConfig;
+pub use toggle::{ToggleConfig, ToggleLook};
+pub use widget_traits::{CompositionEvent, GlobalClick, Widget, WidgetConfig};
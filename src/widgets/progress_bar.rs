@@ -0,0 +1,277 @@
+use super::{Widget, WidgetConfig};
+use crate::{channels::ProgressBarChannel, FontStyle};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics, Image},
+    Result, Timer, Window,
+};
+use std::time::Duration;
+
+///How often per second the animations of a progress bar are updated
+const ANIMATION_STEPS: f32 = 60.0;
+
+///An image that is drawn with nine-slice scaling.
+///The borders keep their size while the center and the edges stretch, so the image can be drawn at any size without distorting its corners
+#[derive(Clone)]
+pub struct NineSlice {
+    pub image: Image,
+    ///The size in pixels of the left, top, right and bottom border inside the image
+    pub borders: (f32, f32, f32, f32),
+}
+
+impl NineSlice {
+    ///An image without borders. It simply gets stretched
+    pub fn stretched(image: Image) -> Self {
+        Self {
+            image,
+            borders: (0.0, 0.0, 0.0, 0.0),
+        }
+    }
+    pub(crate) fn draw(&self, gfx: &mut Graphics, location: Rectangle, tint: Color) {
+        let size = self.image.size();
+        let (left, top, right, bottom) = self.borders;
+        //if the location is smaller than the borders, the borders get shrunk
+        let scale_x = (location.width() / (left + right)).min(1.0);
+        let scale_y = (location.height() / (top + bottom)).min(1.0);
+        let source_x = [0.0, left, size.x - right, size.x];
+        let source_y = [0.0, top, size.y - bottom, size.y];
+        let target_x = [
+            location.pos.x,
+            location.pos.x + left * scale_x,
+            location.pos.x + location.width() - right * scale_x,
+            location.pos.x + location.width(),
+        ];
+        let target_y = [
+            location.pos.y,
+            location.pos.y + top * scale_y,
+            location.pos.y + location.height() - bottom * scale_y,
+            location.pos.y + location.height(),
+        ];
+        for column in 0..3 {
+            for row in 0..3 {
+                let target = Rectangle::new(
+                    Vector::new(target_x[column], target_y[row]),
+                    Vector::new(
+                        target_x[column + 1] - target_x[column],
+                        target_y[row + 1] - target_y[row],
+                    ),
+                );
+                if target.width() <= 0.0 || target.height() <= 0.0 {
+                    continue;
+                }
+                let region = Rectangle::new(
+                    Vector::new(source_x[column], source_y[row]),
+                    Vector::new(
+                        source_x[column + 1] - source_x[column],
+                        source_y[row + 1] - source_y[row],
+                    ),
+                );
+                gfx.draw_subimage_tinted(&self.image, region, target, tint);
+            }
+        }
+    }
+}
+
+///How a progress bar looks
+#[derive(Clone)]
+pub enum ProgressBarLook {
+    Colors {
+        background: Color,
+        fill: Color,
+    },
+    ///The fill image is drawn over the part of the bar that is filled
+    Images {
+        background: NineSlice,
+        fill: NineSlice,
+    },
+}
+
+///From which side a progress bar fills up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillDirection {
+    LeftToRight,
+    RightToLeft,
+    BottomToTop,
+    TopToBottom,
+}
+
+///How a progress bar reacts when its value changes
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarAnimation {
+    ///The bar jumps to the new value
+    None,
+    ///The bar moves towards the new value. Speed is how much of the bar it moves per second
+    Smooth { speed: f32 },
+    ///When the value goes down, the part that got lost stays visible in the given color for a while and then shrinks away.
+    ///Speed is how much of the bar it shrinks per second. With images, the fill image is tinted with the color instead
+    DamageTrail {
+        color: Color,
+        delay: Duration,
+        speed: f32,
+    },
+}
+
+///Text that is drawn on top of a progress bar
+pub struct ProgressBarText {
+    ///The location of the font style is relative to the bar
+    pub font_style: FontStyle,
+    ///Turns the value and the maximum into the text that gets drawn
+    pub format: Box<dyn Fn(f32, f32) -> String>,
+}
+
+///Configures a progress bar. Used to show how full something is, like a health bar or the progress of loading
+pub struct ProgressBarConfig {
+    pub location: Rectangle,
+    ///The value the bar shows. Clones of this channel can be kept by the rest of the game to update the bar
+    pub channel: ProgressBarChannel,
+    pub look: ProgressBarLook,
+    pub direction: FillDirection,
+    pub text: Option<ProgressBarText>,
+    pub animation: BarAnimation,
+}
+
+pub struct ProgressBar {
+    pub location: Rectangle,
+    pub look: ProgressBarLook,
+    pub direction: FillDirection,
+    pub text: Option<ProgressBarText>,
+    pub animation: BarAnimation,
+    channel: ProgressBarChannel,
+    ///The fraction that is drawn as filled
+    shown: f32,
+    ///The fraction up to where the damage trail is drawn
+    trail: f32,
+    trail_delay: Option<Timer>,
+    step_timer: Timer,
+}
+
+impl WidgetConfig<ProgressBarChannel, ProgressBar> for ProgressBarConfig {
+    fn to_widget(self) -> (ProgressBar, ProgressBarChannel) {
+        let fraction = self.channel.fraction();
+        (
+            ProgressBar {
+                location: self.location,
+                look: self.look,
+                direction: self.direction,
+                text: self.text,
+                animation: self.animation,
+                channel: self.channel.clone(),
+                shown: fraction,
+                trail: fraction,
+                trail_delay: None,
+                step_timer: Timer::time_per_second(ANIMATION_STEPS),
+            },
+            self.channel,
+        )
+    }
+}
+
+///Moves current towards target, by at most max_change
+fn move_towards(current: f32, target: f32, max_change: f32) -> f32 {
+    if current < target {
+        (current + max_change).min(target)
+    } else {
+        (current - max_change).max(target)
+    }
+}
+
+impl ProgressBar {
+    ///The part of the bar that is filled up to the given fraction
+    pub fn get_filled_rec(&self, fraction: f32) -> Rectangle {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let mut filled = self.location;
+        match self.direction {
+            FillDirection::LeftToRight => filled.size.x *= fraction,
+            FillDirection::RightToLeft => {
+                filled.size.x *= fraction;
+                filled.pos.x += self.location.width() - filled.size.x;
+            }
+            FillDirection::TopToBottom => filled.size.y *= fraction,
+            FillDirection::BottomToTop => {
+                filled.size.y *= fraction;
+                filled.pos.y += self.location.height() - filled.size.y;
+            }
+        }
+        filled
+    }
+    ///Moves the shown value and the damage trail towards the real value
+    fn animate(&mut self) {
+        let target = self.channel.fraction();
+        let steps = self.step_timer.exhaust().map(|v| v.get()).unwrap_or(0) as f32;
+        match self.animation {
+            BarAnimation::None => {
+                self.shown = target;
+                self.trail = target;
+            }
+            BarAnimation::Smooth { speed } => {
+                self.shown = move_towards(self.shown, target, speed / ANIMATION_STEPS * steps);
+                self.trail = self.shown;
+            }
+            BarAnimation::DamageTrail { delay, speed, .. } => {
+                if target < self.shown {
+                    //got damaged, so the trail waits before it starts to shrink
+                    self.trail_delay = Some(Timer::with_duration(delay));
+                }
+                self.shown = target;
+                if self.trail < target {
+                    self.trail = target;
+                    self.trail_delay = None;
+                }
+                let is_waiting = self
+                    .trail_delay
+                    .as_ref()
+                    .map(|timer| timer.remaining().is_some())
+                    .unwrap_or(false);
+                if !is_waiting {
+                    self.trail_delay = None;
+                    self.trail = move_towards(self.trail, target, speed / ANIMATION_STEPS * steps);
+                }
+            }
+        }
+    }
+}
+
+impl Widget for ProgressBar {
+    fn contains(&self, point: Vector) -> bool {
+        self.location.contains(point)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        false
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        self.animate();
+        let filled = self.get_filled_rec(self.shown);
+        let trail = self.get_filled_rec(self.trail);
+        let trail_color = match self.animation {
+            BarAnimation::DamageTrail { color, .. } if self.trail > self.shown => Some(color),
+            _ => None,
+        };
+        match &self.look {
+            ProgressBarLook::Colors { background, fill } => {
+                gfx.fill_rect(&self.location, *background);
+                if let Some(color) = trail_color {
+                    gfx.fill_rect(&trail, color);
+                }
+                if self.shown > 0.0 {
+                    gfx.fill_rect(&filled, *fill);
+                }
+            }
+            ProgressBarLook::Images { background, fill } => {
+                background.draw(gfx, self.location, Color::WHITE);
+                if let Some(color) = trail_color {
+                    fill.draw(gfx, trail, color);
+                }
+                if self.shown > 0.0 {
+                    fill.draw(gfx, filled, Color::WHITE);
+                }
+            }
+        }
+        if let Some(text) = &self.text {
+            let content = (text.format)(self.channel.get(), self.channel.get_max());
+            let mut font_style = text.font_style.clone();
+            font_style.location += self.location.pos;
+            font_style.draw(gfx, &content)?;
+        }
+        Ok(())
+    }
+}
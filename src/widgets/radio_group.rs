@@ -0,0 +1,218 @@
+use super::{
+    dropdown::{DropDownEntryKind, DropDownValueConfig},
+    Widget, WidgetConfig,
+};
+use crate::channels::{RadioGroupChannel, RadioGroupEvent};
+use quicksilver::{
+    geom::{Circle, Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::Key,
+    Result, Window,
+};
+use std::{
+    cell::RefCell,
+    marker::PhantomData,
+    rc::Rc,
+    sync::mpsc::{self, Sender},
+};
+
+///In which direction the options of a radio group are placed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadioLayout {
+    ///Every option is placed below the previous one
+    Vertical,
+    ///Every option is placed right of the previous one
+    Horizontal,
+}
+
+///Configures a radio group. A list of options where exactly one is selected
+pub struct RadioGroupConfig<T: Clone, ValueConfig: Into<DropDownValueConfig<T>>> {
+    ///A list of options and how to render them. See DropDownValueConfig
+    pub values: Vec<ValueConfig>,
+    ///Where the first option is drawn
+    pub location: Vector,
+    ///The size of every option, including its button
+    pub option_size: Vector,
+    pub layout: RadioLayout,
+    ///What starts as selected. If this option can't be selected, the first option that can is used instead.
+    ///If none of the options can be selected, nothing is
+    pub selected: usize,
+    ///The color of the circle of every button
+    pub border_color: Color,
+    ///The color of the dot inside the button of the selected option
+    pub dot_color: Color,
+    pub t: PhantomData<T>,
+}
+
+pub struct RadioGroup<T: Clone> {
+    pub location: Vector,
+    pub option_size: Vector,
+    pub layout: RadioLayout,
+    pub values: Rc<RefCell<Vec<DropDownValueConfig<T>>>>,
+    pub selected: Rc<RefCell<Option<usize>>>,
+    pub border_color: Color,
+    pub dot_color: Color,
+    pub hover_over: Option<Vector>,
+    event_sender: Sender<RadioGroupEvent<T>>,
+}
+
+impl<T: Clone, X: Into<DropDownValueConfig<T>>> WidgetConfig<RadioGroupChannel<T>, RadioGroup<T>>
+    for RadioGroupConfig<T, X>
+{
+    fn to_widget(self) -> (RadioGroup<T>, RadioGroupChannel<T>) {
+        let values: Vec<DropDownValueConfig<T>> = self.values.into_iter().map(Into::into).collect();
+        let selected = match values.get(self.selected) {
+            Some(value) if value.is_selectable() => Some(self.selected),
+            _ => values.iter().position(|value| value.is_selectable()),
+        };
+        let values = Rc::new(RefCell::new(values));
+        let selected = Rc::new(RefCell::new(selected));
+        let (event_sender, events) = mpsc::channel();
+        (
+            RadioGroup {
+                location: self.location,
+                option_size: self.option_size,
+                layout: self.layout,
+                values: values.clone(),
+                selected: selected.clone(),
+                border_color: self.border_color,
+                dot_color: self.dot_color,
+                hover_over: None,
+                event_sender,
+            },
+            RadioGroupChannel {
+                selected,
+                values,
+                events,
+            },
+        )
+    }
+}
+
+impl<T: Clone> Widget for RadioGroup<T> {
+    fn contains(&self, point: Vector) -> bool {
+        self.vector_to_index(point).is_some()
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn set_hover(&mut self, point: Vector, state: bool) {
+        self.hover_over = if state { Some(point) } else { None };
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let hovered = self.hover_over.and_then(|v| self.vector_to_index(v));
+        let selected = *self.selected.borrow();
+        let values = self.values.borrow();
+        for (index, value) in values.iter().enumerate() {
+            let location = self.get_option_rec(index);
+            let radius = location.height() / 2.0;
+            let button = Circle::new(location.pos + Vector::new(radius, radius), radius);
+            if value.kind == DropDownEntryKind::Option {
+                gfx.stroke_circle(&button, self.border_color);
+                if Some(index) == selected {
+                    gfx.fill_circle(&Circle::new(button.pos, radius / 2.0), self.dot_color);
+                }
+            }
+            let font_style = match &value.hover_font_style {
+                Some(hover) if hovered == Some(index) && value.is_selectable() => hover,
+                _ => &value.normal_font_style,
+            };
+            let mut label = location;
+            label.pos.x += location.height();
+            label.size.x -= location.height();
+            value.draw(gfx, font_style, label, self.border_color, 1.0)?;
+        }
+        Ok(())
+    }
+    fn on_click(&mut self, pos: Vector) {
+        if let Some(index) = self.vector_to_index(pos) {
+            self.select(index);
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if !state {
+            return;
+        }
+        let forward = match key {
+            Key::Down | Key::Right => true,
+            Key::Up | Key::Left => false,
+            _ => return,
+        };
+        let len = self.values.borrow().len();
+        let selected = match *self.selected.borrow() {
+            Some(selected) => selected,
+            None => return,
+        };
+        let next = (1..len)
+            .map(|step| {
+                if forward {
+                    (selected + step) % len
+                } else {
+                    (selected + len - step) % len
+                }
+            })
+            .find(|index| self.is_selectable(*index));
+        if let Some(next) = next {
+            self.select(next);
+        }
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
+
+impl<T: Clone> RadioGroup<T> {
+    ///The area of the option at the given index
+    pub fn get_option_rec(&self, index: usize) -> Rectangle {
+        let mut pos = self.location;
+        match self.layout {
+            RadioLayout::Vertical => pos.y += self.option_size.y * index as f32,
+            RadioLayout::Horizontal => pos.x += self.option_size.x * index as f32,
+        }
+        Rectangle::new(pos, self.option_size)
+    }
+    pub fn vector_to_index(&self, point: Vector) -> Option<usize> {
+        (0..self.values.borrow().len()).find(|index| self.get_option_rec(*index).contains(point))
+    }
+    fn is_selectable(&self, index: usize) -> bool {
+        self.values
+            .borrow()
+            .get(index)
+            .map(|value| value.is_selectable())
+            .unwrap_or(false)
+    }
+    fn select(&mut self, index: usize) {
+        let value = match self.values.borrow().get(index) {
+            Some(value) if value.is_selectable() => value.value.clone(),
+            _ => return,
+        };
+        if self.selected.replace(Some(index)) != Some(index) {
+            let _ = self.event_sender.send(RadioGroupEvent::Changed(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_selected_without_selectable_options() {
+        let (mut radio_group, mut channel) = RadioGroupConfig::<u32, DropDownValueConfig<u32>> {
+            values: Vec::new(),
+            location: Vector::ZERO,
+            option_size: Vector::new(100., 20.),
+            layout: RadioLayout::Vertical,
+            selected: 0,
+            border_color: Color::BLACK,
+            dot_color: Color::BLACK,
+            t: PhantomData,
+        }
+        .to_widget();
+        radio_group.on_key_press(Key::Down, true);
+        assert_eq!(channel.get_selected(), None);
+        assert_eq!(channel.get_value(), None);
+        assert!(!channel.set_selected(0));
+        assert!(channel.events().is_empty());
+    }
+}
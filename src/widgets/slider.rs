@@ -0,0 +1,197 @@
+use super::{Widget, WidgetConfig};
+use crate::channels::SliderChannel;
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics, Image},
+    input::Key,
+    Result, Window,
+};
+
+///In which direction a slider moves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderOrientation {
+    ///The minimum is on the left
+    Horizontal,
+    ///The minimum is at the bottom
+    Vertical,
+}
+
+///How a slider looks
+#[derive(Clone)]
+pub enum SliderLook {
+    Images {
+        track: Image,
+        handle: Image,
+    },
+    Colors {
+        track: Color,
+        ///The color of the part of the track between the minimum and the handle
+        fill: Color,
+        handle: Color,
+    },
+}
+
+///Configures a slider. Used to pick a number between a minimum and a maximum
+pub struct SliderConfig {
+    ///Where the track is drawn
+    pub location: Rectangle,
+    pub min: f32,
+    pub max: f32,
+    ///If set, the value can only be the minimum plus a multiple of step
+    pub step: Option<f32>,
+    ///The value it starts with
+    pub value: f32,
+    pub orientation: SliderOrientation,
+    ///The size of the handle. It is centered on the track
+    pub handle_size: Vector,
+    pub look: SliderLook,
+}
+
+pub struct Slider {
+    pub location: Rectangle,
+    pub orientation: SliderOrientation,
+    pub handle_size: Vector,
+    pub look: SliderLook,
+    channel: SliderChannel,
+    ///Where the handle got grabbed, relative to its center
+    drag_offset: Option<Vector>,
+}
+
+impl WidgetConfig<SliderChannel, Slider> for SliderConfig {
+    fn to_widget(self) -> (Slider, SliderChannel) {
+        let channel = SliderChannel::new(self.value, self.min, self.max, self.step);
+        (
+            Slider {
+                location: self.location,
+                orientation: self.orientation,
+                handle_size: self.handle_size,
+                look: self.look,
+                channel: channel.clone(),
+                drag_offset: None,
+            },
+            channel,
+        )
+    }
+}
+
+impl Slider {
+    ///The center of the handle for the given fraction of the range
+    fn handle_center(&self, fraction: f32) -> Vector {
+        let center = self.location.center();
+        match self.orientation {
+            SliderOrientation::Horizontal => Vector::new(
+                self.location.pos.x + self.location.width() * fraction,
+                center.y,
+            ),
+            SliderOrientation::Vertical => Vector::new(
+                center.x,
+                self.location.pos.y + self.location.height() * (1.0 - fraction),
+            ),
+        }
+    }
+    pub fn get_handle_rec(&self) -> Rectangle {
+        let center = self.handle_center(self.channel.fraction());
+        Rectangle::new(center - self.handle_size / 2.0, self.handle_size)
+    }
+    ///The value that belongs to the given point on the track
+    fn value_at(&self, point: Vector) -> f32 {
+        let fraction = match self.orientation {
+            SliderOrientation::Horizontal => {
+                (point.x - self.location.pos.x) / self.location.width()
+            }
+            SliderOrientation::Vertical => {
+                1.0 - (point.y - self.location.pos.y) / self.location.height()
+            }
+        };
+        let fraction = fraction.clamp(0.0, 1.0);
+        self.channel.min() + (self.channel.max() - self.channel.min()) * fraction
+    }
+    ///How much the arrow keys change the value
+    fn key_step(&self) -> f32 {
+        self.channel
+            .step()
+            .unwrap_or((self.channel.max() - self.channel.min()) / 100.0)
+    }
+}
+
+impl Widget for Slider {
+    fn contains(&self, point: Vector) -> bool {
+        self.location.contains(point) || self.get_handle_rec().contains(point)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let handle = self.get_handle_rec();
+        match &self.look {
+            SliderLook::Images {
+                track,
+                handle: handle_image,
+            } => {
+                gfx.draw_image(track, self.location);
+                gfx.draw_image(handle_image, handle);
+            }
+            SliderLook::Colors {
+                track,
+                fill,
+                handle: handle_color,
+            } => {
+                gfx.fill_rect(&self.location, *track);
+                let center = handle.center();
+                let mut filled = self.location;
+                match self.orientation {
+                    SliderOrientation::Horizontal => {
+                        filled.size.x = center.x - self.location.pos.x;
+                    }
+                    SliderOrientation::Vertical => {
+                        filled.pos.y = center.y;
+                        filled.size.y = self.location.pos.y + self.location.height() - center.y;
+                    }
+                }
+                gfx.fill_rect(&filled, *fill);
+                gfx.fill_rect(&handle, *handle_color);
+            }
+        }
+        Ok(())
+    }
+    fn on_click(&mut self, pos: Vector) {
+        let handle = self.get_handle_rec();
+        if handle.contains(pos) {
+            self.drag_offset = Some(pos - handle.center());
+        } else {
+            //clicking on the track jumps to that point
+            self.drag_offset = Some(Vector::ZERO);
+            let value = self.value_at(pos);
+            self.channel.set_by_user(value);
+        }
+    }
+    fn on_drag(&mut self, pos: Vector) {
+        if let Some(offset) = self.drag_offset {
+            let value = self.value_at(pos - offset);
+            self.channel.set_by_user(value);
+        }
+    }
+    fn on_release(&mut self, _: Vector) {
+        self.drag_offset = None;
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if !state {
+            return;
+        }
+        let current = self.channel.get();
+        let step = self.key_step();
+        let value = match key {
+            Key::Right | Key::Up => current + step,
+            Key::Left | Key::Down => current - step,
+            Key::PageUp => current + step * 10.0,
+            Key::PageDown => current - step * 10.0,
+            Key::Home => self.channel.min(),
+            Key::End => self.channel.max(),
+            _ => return,
+        };
+        self.channel.set_by_user(value);
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    channels::{InputChannel, SpinBoxChannel, SpinBoxNumber},
+    widgets::{
+        input::{Input, ValidationConfig},
+        CompositionEvent, CursorConfig, InputConfig, Widget, WidgetConfig,
+    },
+    FontStyle,
+};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::Key,
+    Result, Window,
+};
+
+///How far the mouse needs to move before a click on the field turns into scrubbing
+const SCRUB_THRESHOLD: f32 = 3.0;
+
+///Configures a spin box. A text field that only accepts numbers, with buttons to increase and decrease it
+pub struct SpinBoxConfig<N: SpinBoxNumber> {
+    pub font: FontStyle,
+    ///The location of the whole widget, including the buttons
+    pub location: Rectangle,
+    pub cursor_config: CursorConfig,
+    ///The number it starts with
+    pub value: N,
+    pub min: N,
+    ///If this is smaller than min, min is used instead
+    pub max: N,
+    ///How much the number changes when the buttons or arrow keys are used
+    pub step: N,
+    ///The amount of decimals that are shown. Ignored for integers
+    pub precision: usize,
+    ///The width of the increase and decrease buttons, drawn on the right side of the field
+    pub button_width: f32,
+    pub button_color: Color,
+    pub arrow_color: Color,
+    ///If set, dragging the mouse horizontally over the field changes the number by this much for every pixel
+    pub scrub_speed: Option<f64>,
+}
+
+pub struct SpinBox<N: SpinBoxNumber> {
+    input: Input,
+    text: InputChannel,
+    channel: SpinBoxChannel<N>,
+    location: Rectangle,
+    button_width: f32,
+    button_color: Color,
+    arrow_color: Color,
+    scrub_speed: Option<f64>,
+    ///Where the drag started and the number at that moment
+    scrub_start: Option<(Vector, N)>,
+    is_scrubbing: bool,
+    has_focus: bool,
+    ///The number that the text shows, used to notice when it got changed through the channel
+    shown: N,
+}
+
+impl<N: SpinBoxNumber> WidgetConfig<SpinBoxChannel<N>, SpinBox<N>> for SpinBoxConfig<N> {
+    fn to_widget(self) -> (SpinBox<N>, SpinBoxChannel<N>) {
+        let channel =
+            SpinBoxChannel::new(self.value, self.min, self.max, self.step, self.precision);
+        let mut input_location = self.location;
+        input_location.size.x -= self.button_width;
+        let (input, text) = InputConfig {
+            font: self.font,
+            placeholder: None,
+            location: input_location,
+            start_value: Some(channel.format()),
+            cursor_config: self.cursor_config,
+            validation: ValidationConfig {
+                filter: Some(Box::new(|c| {
+                    c.is_ascii_digit() || c == '-' || (!N::IS_INTEGER && c == '.')
+                })),
+                ..Default::default()
+            },
+            mask: None,
+            suggestions: None,
+        }
+        .to_widget();
+        (
+            SpinBox {
+                input,
+                text,
+                shown: channel.get(),
+                channel: channel.clone(),
+                location: self.location,
+                button_width: self.button_width,
+                button_color: self.button_color,
+                arrow_color: self.arrow_color,
+                scrub_speed: self.scrub_speed,
+                scrub_start: None,
+                is_scrubbing: false,
+                has_focus: false,
+            },
+            channel,
+        )
+    }
+}
+
+impl<N: SpinBoxNumber> SpinBox<N> {
+    pub fn get_increase_rec(&self) -> Rectangle {
+        Rectangle::new(
+            Vector::new(
+                self.location.pos.x + self.location.width() - self.button_width,
+                self.location.pos.y,
+            ),
+            Vector::new(self.button_width, self.location.height() / 2.0),
+        )
+    }
+    pub fn get_decrease_rec(&self) -> Rectangle {
+        let mut rec = self.get_increase_rec();
+        rec.pos.y += rec.size.y;
+        rec
+    }
+    ///Changes the number and shows it
+    fn set_value(&mut self, value: N) {
+        self.channel.set_by_user(value);
+        self.show_value();
+    }
+    fn show_value(&mut self) {
+        self.text.set(self.channel.format());
+        self.shown = self.channel.get();
+    }
+    fn step_by(&mut self, steps: i32) {
+        let value = self.channel.get().add_steps(self.channel.step(), steps);
+        self.set_value(value);
+    }
+    ///Turns the typed text into the number. If it isn't a number, the old number is shown again
+    fn commit_text(&mut self) {
+        match self.channel.parse(&self.text.get()) {
+            //if the text didn't change, the number may have been changed through the channel instead
+            Some(value) if value != self.shown => self.set_value(value),
+            _ => self.show_value(),
+        }
+    }
+    fn draw_arrow(&self, gfx: &mut Graphics, button: Rectangle, up: bool) {
+        let center = button.center();
+        let size = button.width().min(button.height()) / 4.0;
+        //a triangle made out of rectangles that get smaller towards the tip
+        let rows = size.ceil().max(1.0) as usize;
+        for row in 0..rows {
+            let width = size * 2.0 * (rows - row) as f32 / rows as f32;
+            let y = if up {
+                center.y + size / 2.0 - row as f32
+            } else {
+                center.y - size / 2.0 + row as f32
+            };
+            gfx.fill_rect(
+                &Rectangle::new(
+                    Vector::new(center.x - width / 2.0, y),
+                    Vector::new(width, 1.0),
+                ),
+                self.arrow_color,
+            );
+        }
+    }
+}
+
+impl<N: SpinBoxNumber> Widget for SpinBox<N> {
+    fn contains(&self, pos: Vector) -> bool {
+        self.location.contains(pos)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, window: &Window) -> Result<()> {
+        //the number got changed through the channel
+        if self.channel.get() != self.shown && !self.has_focus {
+            self.show_value();
+        }
+        self.input.render(gfx, window)?;
+        for (button, up) in [
+            (self.get_increase_rec(), true),
+            (self.get_decrease_rec(), false),
+        ] {
+            gfx.fill_rect(&button, self.button_color);
+            gfx.stroke_rect(&button, Color::BLACK);
+            self.draw_arrow(gfx, button, up);
+        }
+        Ok(())
+    }
+    fn get_cursor_on_hover(&self, pos: Vector) -> quicksilver::CursorIcon {
+        if self.get_increase_rec().contains(pos) || self.get_decrease_rec().contains(pos) {
+            quicksilver::CursorIcon::Hand
+        } else if self.scrub_speed.is_some() {
+            quicksilver::CursorIcon::EwResize
+        } else {
+            self.input.get_cursor_on_hover(pos)
+        }
+    }
+    fn set_focus(&mut self, pos: Vector, focus: bool) {
+        self.input.set_focus(pos, focus);
+        if !focus && self.has_focus {
+            self.commit_text();
+        }
+        self.has_focus = focus;
+    }
+    fn set_hover(&mut self, pos: Vector, state: bool) {
+        self.input.set_hover(pos, state)
+    }
+    fn on_click(&mut self, pos: Vector) {
+        if self.get_increase_rec().contains(pos) {
+            self.commit_text();
+            self.step_by(1);
+        } else if self.get_decrease_rec().contains(pos) {
+            self.commit_text();
+            self.step_by(-1);
+        } else {
+            self.input.on_click(pos);
+            if self.scrub_speed.is_some() {
+                self.scrub_start = Some((pos, self.channel.get()));
+            }
+        }
+    }
+    fn on_drag(&mut self, pos: Vector) {
+        let (speed, (start, start_value)) = match (self.scrub_speed, self.scrub_start) {
+            (Some(speed), Some(start)) => (speed, start),
+            _ => return,
+        };
+        let moved = pos.x - start.x;
+        if !self.is_scrubbing && moved.abs() < SCRUB_THRESHOLD {
+            return;
+        }
+        self.is_scrubbing = true;
+        let value = start_value.to_f64() + moved as f64 * speed;
+        self.set_value(N::from_f64(value));
+    }
+    fn on_release(&mut self, _: Vector) {
+        self.scrub_start = None;
+        self.is_scrubbing = false;
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        let steps = match key {
+            Key::Up => 1,
+            Key::Down => -1,
+            Key::PageUp => 10,
+            Key::PageDown => -10,
+            _ => 0,
+        };
+        if steps != 0 {
+            if state {
+                self.commit_text();
+                self.step_by(steps);
+            }
+            return;
+        }
+        self.input.on_key_press(key, state);
+        if (key == Key::Return || key == Key::NumpadEnter) && state {
+            self.commit_text();
+        }
+    }
+    fn on_typed(&mut self, typed: char) {
+        self.input.on_typed(typed);
+    }
+    fn on_composition(&mut self, event: &CompositionEvent) {
+        self.input.on_composition(event);
+    }
+    fn get_caret_rect(&self) -> Option<Rectangle> {
+        self.input.get_caret_rect()
+    }
+}
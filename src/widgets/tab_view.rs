@@ -0,0 +1,169 @@
+use super::{
+    concealer_manager::ConcealerManager, ButtonConfig, ConcealerConfig, ConcealerManagerConfig,
+    Widget, WidgetConfig,
+};
+use crate::{channels::TabViewChannel, FontStyle, SingularLayerId};
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::{Color, Graphics, Image},
+    input::Key,
+    Result, Window,
+};
+
+///A single tab of a tab view
+pub struct TabConfig {
+    ///The text shown on the header of the tab
+    pub title: String,
+    ///The layer that is shown while this tab is open
+    pub layer: SingularLayerId,
+}
+
+///Configures a tab view. A row of headers where clicking on one shows its layer and hides the others.
+///There is always one tab open. Once it has focus, control+tab opens the next tab and control+shift+tab the previous one
+pub struct TabViewConfig {
+    pub tabs: Vec<TabConfig>,
+    ///Where the first header is drawn. Every other header is placed right of the previous one
+    pub location: Vector,
+    ///The size of every header
+    pub header_size: Vector,
+    ///The image drawn as the background of every header
+    pub background: Image,
+    ///The style of the titles. The location is relative to the header
+    pub font_style: FontStyle,
+    ///optionally, the color that the headers of closed tabs blend with
+    pub blend_color: Option<Color>,
+    ///optionally, the color the header blends with if the user hovers over it
+    pub hover_color: Option<Color>,
+    ///The color that the header of the open tab blends with
+    pub active_color: Color,
+    ///The tab that starts open
+    pub active: usize,
+}
+
+pub struct TabView {
+    manager: ConcealerManager,
+    pub blend_color: Option<Color>,
+    pub active_color: Color,
+    shift_down: bool,
+    control_down: bool,
+}
+
+impl WidgetConfig<TabViewChannel, TabView> for TabViewConfig {
+    fn to_widget(self) -> (TabView, TabViewChannel) {
+        let location = self.location;
+        let header_size = self.header_size;
+        let font_style = self.font_style;
+        let background = self.background;
+        let blend_color = self.blend_color;
+        let hover_color = self.hover_color;
+        let concealers = self
+            .tabs
+            .into_iter()
+            .enumerate()
+            .map(|(index, tab)| ConcealerConfig {
+                button: ButtonConfig {
+                    text: tab.title,
+                    font_style: font_style.clone(),
+                    background: background.clone(),
+                    background_location: Rectangle::new(
+                        location + Vector::new(header_size.x * index as f32, 0.),
+                        header_size,
+                    ),
+                    blend_color,
+                    hover_color,
+                },
+                layer: tab.layer,
+                is_popover: false,
+                open_on_hover: None,
+            })
+            .collect();
+        let (mut manager, channel) = ConcealerManagerConfig { concealers }.to_widget();
+        manager.set_active(Some(self.active));
+        //the given tab doesn't exist, so the first one gets opened instead
+        if manager.active.borrow().is_none() {
+            manager.set_active(Some(0));
+        }
+        (
+            TabView {
+                manager,
+                blend_color,
+                active_color: self.active_color,
+                shift_down: false,
+                control_down: false,
+            },
+            TabViewChannel { manager: channel },
+        )
+    }
+}
+
+impl TabView {
+    fn get_active(&self) -> usize {
+        self.manager.active.borrow().unwrap_or(0)
+    }
+    ///Opens the next or previous tab, wrapping around at the ends
+    fn open_relative(&mut self, forward: bool) {
+        let len = self.manager.concealers_mut().len();
+        if len == 0 {
+            return;
+        }
+        let active = self.get_active();
+        let next = if forward {
+            (active + 1) % len
+        } else {
+            (active + len - 1) % len
+        };
+        self.manager.select(Some(next));
+    }
+}
+
+impl Widget for TabView {
+    fn contains(&self, pos: Vector) -> bool {
+        self.manager.contains(pos)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, w: &Window) -> Result<()> {
+        let active = self.get_active();
+        let blend_color = self.blend_color;
+        let active_color = self.active_color;
+        for (index, concealer) in self.manager.concealers_mut().iter_mut().enumerate() {
+            concealer.button.background.button.color = if index == active {
+                Some(active_color)
+            } else {
+                blend_color
+            };
+        }
+        self.manager.render(gfx, w)
+    }
+    fn get_cursor_on_hover(&self, pos: Vector) -> quicksilver::CursorIcon {
+        self.manager.get_cursor_on_hover(pos)
+    }
+    fn set_focus(&mut self, _: Vector, focus: bool) {
+        if !focus {
+            self.shift_down = false;
+            self.control_down = false;
+        }
+    }
+    fn set_hover(&mut self, pos: Vector, state: bool) {
+        for concealer in self.manager.concealers_mut() {
+            let hover = state && concealer.contains(pos);
+            concealer.set_hover(pos, hover);
+        }
+    }
+    fn on_click(&mut self, pos: Vector) {
+        //clicking on the open tab would close it, but there always needs to be a tab open
+        match self.manager.get_button_index(pos) {
+            Some(index) if index != self.get_active() => self.manager.on_click(pos),
+            _ => {}
+        }
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        match key {
+            Key::LShift | Key::RShift => self.shift_down = state,
+            Key::LControl | Key::RControl => self.control_down = state,
+            Key::Tab if state && self.control_down => self.open_relative(!self.shift_down),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,561 @@
+use crate::{
+    channels::{InputChannel, InputEvent},
+    widgets::{
+        input::{CursorConfig, ValidationConfig},
+        CompositionEvent, Widget, WidgetConfig,
+    },
+    FontStyle,
+};
+use quicksilver::{
+    geom::{Rectangle, Shape, Vector},
+    graphics::{Color, Graphics},
+    input::{Key, ScrollDelta},
+    Result, Window,
+};
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+///A multiline text field.
+///Lines that are too long get wrapped and the text scrolls once it no longer fits.
+pub struct TextAreaConfig {
+    ///The style used to draw the text. Its location is ignored
+    pub font: FontStyle,
+    ///The location and size of the text area
+    pub location: Rectangle,
+    pub start_value: Option<String>,
+    pub cursor_config: CursorConfig,
+    ///Limits what the user can type and decides when the value is valid
+    pub validation: ValidationConfig,
+    ///The color that is drawn behind selected text
+    pub selection_color: Color,
+}
+
+///A line as it is drawn on the screen, after wrapping
+struct VisualLine {
+    ///the index of the first grapheme of this line
+    start: usize,
+    ///where the cursor is drawn in front of every grapheme, relative to the start of the line.
+    ///Contains one more entry than the line has graphemes.
+    offsets: Vec<f32>,
+}
+
+impl VisualLine {
+    fn new(start: usize, offsets: &[f32]) -> Self {
+        let line_start = offsets[0];
+        Self {
+            start,
+            offsets: offsets.iter().map(|offset| offset - line_start).collect(),
+        }
+    }
+    fn end(&self) -> usize {
+        self.start + self.offsets.len() - 1
+    }
+}
+
+pub struct TextArea {
+    config: TextAreaConfig,
+    value: InputChannel,
+    invalid_color: Option<Color>,
+    cursor_at: usize,
+    selection_start: Option<usize>,
+    wanted_x: Option<f32>,
+    lines: Vec<VisualLine>,
+    line_height: f32,
+    first_visible_line: usize,
+    scroll_remainder: f32,
+    scroll_to_cursor: bool,
+    cursor_is_visible: bool,
+    has_focus: bool,
+    shift_held: bool,
+    preedit: String,
+}
+
+impl WidgetConfig<InputChannel, TextArea> for TextAreaConfig {
+    fn to_widget(mut self) -> (TextArea, InputChannel) {
+        let validation = std::mem::take(&mut self.validation);
+        let invalid_color = validation.invalid_color;
+        let value = InputChannel::with_validation(
+            self.start_value.clone().unwrap_or_else(|| "".into()),
+            validation,
+        );
+        let line_height = self.font.font.size;
+        (
+            TextArea {
+                config: self,
+                value: InputChannel::clone(&value),
+                invalid_color,
+                cursor_at: 0,
+                selection_start: None,
+                wanted_x: None,
+                lines: Vec::new(),
+                line_height,
+                first_visible_line: 0,
+                scroll_remainder: 0.0,
+                scroll_to_cursor: false,
+                cursor_is_visible: true,
+                has_focus: false,
+                shift_held: false,
+                preedit: String::new(),
+            },
+            value,
+        )
+    }
+}
+
+///Splits a single line of text into multiple lines so every line fits inside the given width.
+///Lines are broken after whitespace if possible, otherwise in the middle of the word.
+fn wrap_line(
+    graphemes: &[&str],
+    offsets: &[f32],
+    width: f32,
+    start: usize,
+    lines: &mut Vec<VisualLine>,
+) {
+    let mut line_start = 0;
+    let mut word_start = None;
+    for (index, grapheme) in graphemes.iter().enumerate() {
+        if grapheme.chars().all(char::is_whitespace) {
+            word_start = Some(index + 1);
+            continue;
+        }
+        if index > line_start && offsets[index + 1] - offsets[line_start] > width {
+            let break_at = word_start.filter(|v| *v > line_start).unwrap_or(index);
+            lines.push(VisualLine::new(
+                start + line_start,
+                &offsets[line_start..=break_at],
+            ));
+            line_start = break_at;
+            word_start = None;
+        }
+    }
+    lines.push(VisualLine::new(start + line_start, &offsets[line_start..]));
+}
+
+impl TextArea {
+    ///The graphemes that are drawn, which includes the text that is still being composed
+    fn display_graphemes(&self) -> Vec<String> {
+        let mut graphemes = self.value.graphemes();
+        let cursor_at = self.cursor_at.min(graphemes.len());
+        graphemes.splice(
+            cursor_at..cursor_at,
+            self.preedit.graphemes(true).map(String::from),
+        );
+        graphemes
+    }
+
+    ///Where the cursor is drawn. It is placed behind the text that is being composed
+    fn display_cursor(&self) -> usize {
+        self.cursor_at + self.preedit.graphemes(true).count()
+    }
+
+    fn layout(&mut self, gfx: &mut Graphics) -> Result<()> {
+        let value = self.display_graphemes();
+        let graphemes: Vec<&str> = value.iter().map(String::as_str).collect();
+        let width = self.config.location.width();
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in graphemes.split(|grapheme| *grapheme == "\n" || *grapheme == "\r\n") {
+            //the font works with characters, so only keep the offsets in front of every grapheme
+            let char_offsets = self.config.font.font.caret_offsets(gfx, &line.concat())?;
+            let mut offsets = Vec::with_capacity(line.len() + 1);
+            let mut char_index = 0;
+            for grapheme in line {
+                offsets.push(char_offsets[char_index]);
+                char_index += grapheme.chars().count();
+            }
+            offsets.push(char_offsets[char_index]);
+            wrap_line(line, &offsets, width, start, &mut lines);
+            start += line.len() + 1;
+        }
+        self.lines = lines;
+        self.line_height = self.config.font.font.line_height(gfx)?;
+        Ok(())
+    }
+
+    fn visible_line_count(&self) -> usize {
+        ((self.config.location.height() / self.line_height).floor() as usize).max(1)
+    }
+
+    fn line_of(&self, index: usize) -> usize {
+        self.lines
+            .iter()
+            .rposition(|line| line.start <= index)
+            .unwrap_or(0)
+    }
+
+    fn x_of(&self, index: usize) -> f32 {
+        self.lines
+            .get(self.line_of(index))
+            .and_then(|line| line.offsets.get(index.saturating_sub(line.start)))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    ///Finds the place in the given line that is closest to x
+    fn index_at(&self, line_index: usize, x: f32) -> usize {
+        let line = match self.lines.get(line_index) {
+            Some(line) => line,
+            None => return self.value.grapheme_count(),
+        };
+        //the end of a wrapped line is the same place as the start of the next line
+        let is_wrapped = self
+            .lines
+            .get(line_index + 1)
+            .map(|next| next.start == line.end())
+            .unwrap_or(false);
+        let last = if is_wrapped {
+            line.offsets.len() - 2
+        } else {
+            line.offsets.len() - 1
+        };
+        let closest = line.offsets[..=last]
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - x)
+                    .abs()
+                    .partial_cmp(&(*b - x).abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        line.start + closest
+    }
+
+    fn selection(&self) -> Option<Range<usize>> {
+        let start = self.selection_start?;
+        let range = start.min(self.cursor_at)..start.max(self.cursor_at);
+        if range.start == range.end {
+            None
+        } else {
+            Some(range)
+        }
+    }
+
+    fn move_cursor(&mut self, new_position: usize) {
+        if self.shift_held {
+            if self.selection_start.is_none() {
+                self.selection_start = Some(self.cursor_at);
+            }
+        } else {
+            self.selection_start = None;
+        }
+        self.cursor_at = new_position.min(self.value.grapheme_count());
+        self.scroll_to_cursor = true;
+    }
+
+    fn move_vertical(&mut self, down: bool) {
+        let line = self.line_of(self.cursor_at);
+        let x = self.wanted_x.unwrap_or_else(|| self.x_of(self.cursor_at));
+        let target = if down {
+            line + 1
+        } else {
+            match line.checked_sub(1) {
+                Some(line) => line,
+                None => return self.move_cursor(0),
+            }
+        };
+        if target >= self.lines.len() {
+            let end = self.value.grapheme_count();
+            return self.move_cursor(end);
+        }
+        let new_position = self.index_at(target, x);
+        self.move_cursor(new_position);
+        self.wanted_x = Some(x);
+    }
+
+    fn remove_selection(&mut self) -> bool {
+        match self.selection() {
+            Some(range) => {
+                self.value.remove_graphemes(range.clone());
+                self.cursor_at = range.start;
+                self.selection_start = None;
+                self.scroll_to_cursor = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, text: &str) {
+        self.remove_selection();
+        let old_count = self.value.grapheme_count();
+        if self.value.insert_str_at_grapheme(self.cursor_at, text) {
+            self.cursor_at =
+                (self.cursor_at + self.value.grapheme_count()).saturating_sub(old_count);
+        }
+        self.scroll_to_cursor = true;
+    }
+
+    fn clamp_scroll(&mut self) {
+        let visible = self.visible_line_count();
+        if self.scroll_to_cursor {
+            let line = self.line_of(self.display_cursor());
+            if line < self.first_visible_line {
+                self.first_visible_line = line;
+            } else if line >= self.first_visible_line + visible {
+                self.first_visible_line = line + 1 - visible;
+            }
+            self.scroll_to_cursor = false;
+        }
+        self.first_visible_line = self
+            .first_visible_line
+            .min(self.lines.len().saturating_sub(visible));
+    }
+
+    fn draw_text(&mut self, gfx: &mut Graphics) -> Result<()> {
+        let graphemes = self.display_graphemes();
+        let selection = self.selection();
+        let preedit = self.cursor_at..self.display_cursor();
+        let location = self.config.location;
+        let last_line = (self.first_visible_line + self.visible_line_count()).min(self.lines.len());
+        for (row, line) in self.lines[self.first_visible_line..last_line]
+            .iter()
+            .enumerate()
+        {
+            let top = location.pos.y + row as f32 * self.line_height;
+            if let Some(selection) = &selection {
+                let start = selection.start.max(line.start);
+                let end = selection.end.min(line.end());
+                if start < end {
+                    let left = line.offsets[start - line.start];
+                    let right = line.offsets[end - line.start];
+                    gfx.fill_rect(
+                        &Rectangle::new(
+                            Vector::new(location.pos.x + left, top),
+                            Vector::new(right - left, self.line_height),
+                        ),
+                        self.config.selection_color,
+                    );
+                }
+            }
+            let start = preedit.start.max(line.start);
+            let end = preedit.end.min(line.end());
+            if start < end {
+                let left = line.offsets[start - line.start];
+                let right = line.offsets[end - line.start];
+                gfx.fill_rect(
+                    &Rectangle::new(
+                        Vector::new(
+                            location.pos.x + left,
+                            top + self.config.font.font.size + 2.0,
+                        ),
+                        Vector::new(right - left, 1.0),
+                    ),
+                    self.config.font.color,
+                );
+            }
+            let text = graphemes[line.start..line.end().min(graphemes.len())].concat();
+            let font = &self.config.font;
+            font.font
+                .layout_glyphs(gfx, &text, None, |gfx, layout_glyph| {
+                    let glyph_bounds = layout_glyph.glyph.bounds;
+                    let pos = Vector::new(
+                        layout_glyph.position.x + location.pos.x,
+                        layout_glyph.position.y + font.font.size + top,
+                    );
+                    let glyph_size =
+                        Vector::new(glyph_bounds.width as f32, glyph_bounds.height as f32);
+                    let region = Rectangle::new(
+                        Vector::new(glyph_bounds.x as f32, glyph_bounds.y as f32),
+                        glyph_size,
+                    );
+                    gfx.draw_subimage_tinted(
+                        &layout_glyph.image,
+                        region,
+                        Rectangle::new(pos, glyph_size),
+                        font.color,
+                    );
+                })?;
+        }
+        Ok(())
+    }
+
+    ///Where the cursor is drawn. Returns None if it is scrolled out of view
+    fn caret(&self) -> Option<Rectangle> {
+        let cursor = self.display_cursor();
+        let line = self.line_of(cursor);
+        if line < self.first_visible_line
+            || line >= self.first_visible_line + self.visible_line_count()
+        {
+            return None;
+        }
+        let row = (line - self.first_visible_line) as f32;
+        Some(Rectangle::new(
+            Vector::new(
+                self.config.location.pos.x + self.x_of(cursor),
+                self.config.location.pos.y + row * self.line_height,
+            ),
+            Vector::new(self.config.cursor_config.thickness, self.line_height),
+        ))
+    }
+
+    fn draw_cursor(&mut self, gfx: &mut Graphics) {
+        if !self.has_focus {
+            return;
+        }
+        self.cursor_is_visible = self.config.cursor_config.blink(self.cursor_is_visible);
+        if !self.cursor_is_visible {
+            return;
+        }
+        if let Some(caret) = self.caret() {
+            gfx.fill_rect(&caret, self.config.cursor_config.color);
+        }
+    }
+}
+
+impl Widget for TextArea {
+    fn contains(&self, pos: Vector) -> bool {
+        self.config.location.contains(pos)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let border_color = match self.invalid_color {
+            Some(color) if !self.value.is_valid() => color,
+            _ => Color::BLACK,
+        };
+        gfx.stroke_rect(&self.config.location, border_color);
+        self.cursor_at = self.cursor_at.min(self.value.grapheme_count());
+        self.layout(gfx)?;
+        self.clamp_scroll();
+        self.draw_text(gfx)?;
+        self.draw_cursor(gfx);
+        Ok(())
+    }
+    fn set_focus(&mut self, _: Vector, focus: bool) {
+        if focus {
+            self.config.cursor_config.time_off.reset();
+            self.config.cursor_config.time_on.reset();
+            self.cursor_is_visible = true;
+        } else {
+            self.cursor_is_visible = false;
+            self.shift_held = false;
+            self.preedit.clear();
+        }
+        if focus != self.has_focus {
+            self.value.send_event(if focus {
+                InputEvent::FocusGained
+            } else {
+                InputEvent::FocusLost
+            });
+        }
+        self.has_focus = focus
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Text
+    }
+    fn on_click(&mut self, location: Vector) {
+        if self.lines.is_empty() || !self.preedit.is_empty() {
+            return;
+        }
+        let row = ((location.y - self.config.location.pos.y) / self.line_height).max(0.0);
+        let line = (self.first_visible_line + row as usize).min(self.lines.len() - 1);
+        let new_position = self.index_at(line, location.x - self.config.location.pos.x);
+        self.move_cursor(new_position);
+        self.wanted_x = None;
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if key == Key::LShift || key == Key::RShift {
+            self.shift_held = state;
+        }
+        //while composing, the keys are used by the input method
+        if !state || !self.preedit.is_empty() {
+            return;
+        }
+        let old_value = self.value.get();
+        match key {
+            Key::Up => return self.move_vertical(false),
+            Key::Down => return self.move_vertical(true),
+            Key::Left => {
+                let new_position = self.cursor_at.saturating_sub(1);
+                self.move_cursor(new_position)
+            }
+            Key::Right => self.move_cursor(self.cursor_at + 1),
+            Key::Home => {
+                let line = self.line_of(self.cursor_at);
+                if let Some(start) = self.lines.get(line).map(|line| line.start) {
+                    self.move_cursor(start)
+                }
+            }
+            Key::End => {
+                let line = self.line_of(self.cursor_at);
+                let end = match (self.lines.get(line), self.lines.get(line + 1)) {
+                    //the end of a wrapped line is the start of the next, so stay in front of the last character
+                    (Some(current), Some(next)) if next.start == current.end() => next.start - 1,
+                    (Some(current), _) => current.end(),
+                    (None, _) => return,
+                };
+                self.move_cursor(end)
+            }
+            Key::Return | Key::NumpadEnter => self.insert("\n"),
+            Key::Back => {
+                if !self.remove_selection() && self.cursor_at > 0 {
+                    let old_count = self.value.grapheme_count();
+                    self.value.remove_grapheme_at(self.cursor_at - 1);
+                    self.cursor_at -= old_count - self.value.grapheme_count();
+                    self.scroll_to_cursor = true;
+                }
+            }
+            Key::Delete => {
+                if !self.remove_selection() && self.cursor_at < self.value.grapheme_count() {
+                    self.value.remove_grapheme_at(self.cursor_at);
+                    self.scroll_to_cursor = true;
+                }
+            }
+            _ => return,
+        }
+        self.value.send_if_changed(&old_value);
+        self.wanted_x = None;
+    }
+    fn on_typed(&mut self, typed_char: char) {
+        if typed_char.is_control() || !self.preedit.is_empty() {
+            return;
+        }
+        let old_value = self.value.get();
+        self.insert(typed_char.encode_utf8(&mut [0; 4]));
+        self.value.send_if_changed(&old_value);
+        self.wanted_x = None;
+    }
+    fn on_composition(&mut self, event: &CompositionEvent) {
+        let old_value = self.value.get();
+        match event {
+            CompositionEvent::Start => {
+                self.remove_selection();
+                self.preedit.clear();
+            }
+            CompositionEvent::Update(preedit) => {
+                self.remove_selection();
+                self.preedit = preedit.clone();
+            }
+            CompositionEvent::Commit(text) => {
+                self.preedit.clear();
+                let text: String = text
+                    .chars()
+                    .filter(|character| *character == '\n' || !character.is_control())
+                    .collect();
+                self.insert(&text);
+            }
+        }
+        self.scroll_to_cursor = true;
+        self.value.send_if_changed(&old_value);
+    }
+    fn get_caret_rect(&self) -> Option<Rectangle> {
+        if self.has_focus {
+            self.caret()
+        } else {
+            None
+        }
+    }
+    fn on_scroll(&mut self, delta: &ScrollDelta) {
+        let lines = match delta {
+            ScrollDelta::Lines(amount) => -amount.y,
+            ScrollDelta::Pixels(amount) => -amount.y / self.line_height,
+        };
+        self.scroll_remainder += lines;
+        let whole_lines = self.scroll_remainder.trunc();
+        self.scroll_remainder -= whole_lines;
+        self.first_visible_line = (self.first_visible_line as f32 + whole_lines).max(0.0) as usize;
+    }
+}
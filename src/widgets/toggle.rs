@@ -0,0 +1,123 @@
+use super::{
+    checkbox::{Label, LabelConfig},
+    Widget, WidgetConfig,
+};
+use crate::channels::{CheckState, CheckboxChannel};
+use quicksilver::{
+    geom::{Circle, Rectangle, Shape, Vector},
+    graphics::{Color, Graphics, Image},
+    input::Key,
+    Result, Window,
+};
+
+///How a toggle switch looks
+#[derive(Clone)]
+pub enum ToggleLook {
+    ///Draws an image for both states
+    Images { off: Image, on: Image },
+    ///Draws a track with a round knob that moves to the right when switched on.
+    ///If the state is indeterminate the knob is drawn in the middle
+    Drawn {
+        off_color: Color,
+        on_color: Color,
+        knob_color: Color,
+    },
+}
+
+///Configures a toggle switch. It works the same as a checkbox, but looks like a switch
+pub struct ToggleConfig {
+    ///Where the switch is drawn
+    pub location: Rectangle,
+    pub look: ToggleLook,
+    ///Optionally, text that is drawn next to the switch
+    pub label: Option<LabelConfig>,
+    ///If it starts switched on
+    pub is_on: bool,
+}
+
+pub struct Toggle {
+    pub location: Rectangle,
+    pub look: ToggleLook,
+    label: Option<Label>,
+    channel: CheckboxChannel,
+}
+
+impl WidgetConfig<CheckboxChannel, Toggle> for ToggleConfig {
+    fn to_widget(self) -> (Toggle, CheckboxChannel) {
+        let mut channel = CheckboxChannel::new(CheckState::Unchecked);
+        channel.set_checked(self.is_on);
+        let location = self.location;
+        (
+            Toggle {
+                location,
+                look: self.look,
+                label: self.label.map(|label| Label::new(label, location)),
+                channel: channel.clone(),
+            },
+            channel,
+        )
+    }
+}
+
+impl Widget for Toggle {
+    fn contains(&self, point: Vector) -> bool {
+        self.location.contains(point)
+            || self
+                .label
+                .as_ref()
+                .map(|label| label.contains(point))
+                .unwrap_or(false)
+    }
+    fn is_focusable(&self, _: Vector) -> bool {
+        true
+    }
+    fn render(&mut self, gfx: &mut Graphics, _: &Window) -> Result<()> {
+        let state = self.channel.get_state();
+        match &self.look {
+            ToggleLook::Images { off, on } => {
+                let image = if state == CheckState::Checked {
+                    on
+                } else {
+                    off
+                };
+                gfx.draw_image(image, self.location);
+            }
+            ToggleLook::Drawn {
+                off_color,
+                on_color,
+                knob_color,
+            } => {
+                let track_color = if state == CheckState::Checked {
+                    on_color
+                } else {
+                    off_color
+                };
+                gfx.fill_rect(&self.location, *track_color);
+                let radius = self.location.height() / 2.0;
+                let travel = (self.location.width() - self.location.height()).max(0.0);
+                let moved = match state {
+                    CheckState::Unchecked => 0.0,
+                    CheckState::Indeterminate => travel / 2.0,
+                    CheckState::Checked => travel,
+                };
+                let center = self.location.pos + Vector::new(radius + moved, radius);
+                gfx.fill_circle(&Circle::new(center, radius), *knob_color);
+            }
+        }
+        if let Some(label) = &mut self.label {
+            label.render(gfx)?;
+        }
+        Ok(())
+    }
+    fn on_click(&mut self, _: Vector) {
+        self.channel.toggle();
+    }
+    fn on_key_press(&mut self, key: Key, state: bool) {
+        if key == Key::Space && state {
+            self.channel.toggle();
+        }
+    }
+    fn get_cursor_on_hover(&self, _: Vector) -> quicksilver::CursorIcon {
+        quicksilver::CursorIcon::Hand
+    }
+}
@@ -1,4 +1,36 @@
-use quicksilver::{geom::Vector, graphics::Graphics, Window};
+use crate::{LayerId, LayerNummerId};
+use quicksilver::{
+    geom::{Rectangle, Vector},
+    graphics::Graphics,
+    Window,
+};
+
+///Text composition events from an input method, used to type text that can't be typed directly like Chinese or Japanese.
+///See Context::composition
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompositionEvent {
+    ///The user started composing text
+    Start,
+    ///The text that is being composed changed. Contains the full text that is being composed
+    Update(String),
+    ///The user finished composing. Contains the text that needs to be inserted
+    Commit(String),
+}
+
+///A click that every widget gets told about, even if it happened somewhere else. See Widget::on_global_click
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalClick {
+    ///Where the user clicked, relative to the layer of the widget that gets told about it
+    pub location: Vector,
+    pub(crate) layer: Option<LayerNummerId>,
+}
+
+impl GlobalClick {
+    ///Get if the click was on a widget that is on the given layer
+    pub fn is_on_layer(&self, layer: &LayerId) -> bool {
+        self.layer == Some(layer.id())
+    }
+}
 
 ///Turns a simple configuration into a real widget that can be drawn and interacted with.
 pub trait WidgetConfig<R: Sized, W: Widget> {
@@ -16,6 +48,24 @@ pub trait Widget {
     fn set_focus(&mut self, _: Vector, _: bool) {}
     fn set_hover(&mut self, _: Vector, _: bool) {}
     fn on_click(&mut self, _location: Vector) {}
+    ///Called when the mouse moves while the left button is held down after clicking on this widget
+    fn on_drag(&mut self, _location: Vector) {}
+    ///Called when the left button is released after clicking on this widget
+    fn on_release(&mut self, _location: Vector) {}
     fn on_key_press(&mut self, _key: quicksilver::input::Key, _state: bool) {}
     fn on_typed(&mut self, _char: char) {}
+    fn on_scroll(&mut self, _delta: &quicksilver::input::ScrollDelta) {}
+    fn on_composition(&mut self, _event: &CompositionEvent) {}
+    ///Called after every click, also when another widget got clicked.
+    ///Used by widgets that close when the user clicks somewhere else
+    fn on_global_click(&mut self, _click: &GlobalClick) {}
+    ///Called for every key press, also when another widget has focus
+    fn on_global_key_press(&mut self, _key: quicksilver::input::Key, _state: bool) {}
+    ///Where the text cursor is drawn, if the widget has one
+    fn get_caret_rect(&self) -> Option<Rectangle> {
+        None
+    }
+    ///Called before rendering with the offset of the layer the widget is on. See LayerId::set_offset.
+    ///Used by widgets that need to know where they end up inside the window
+    fn set_layer_offset(&mut self, _offset: Vector) {}
 }